@@ -1,5 +1,7 @@
 use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
 
+use tiny_artnet::{IndicatorState, ReplyDestination, Style};
+
 fn main() {
     // Use the default ArtNet Port
     let port = tiny_artnet::PORT;
@@ -25,11 +27,39 @@ fn main() {
         port
     );
 
+    // Describe this node to controllers
+    let node = tiny_artnet::Node::new(
+        tiny_artnet::PollReply {
+            ip_address: &ip_address,
+            port,
+            firmware_version: 0x0001,
+            short_name: "Example Node",
+            long_name: "Tiny Artnet Example Node",
+            mac_address: &mac_address_bytes,
+            // This Node has one port
+            num_ports: 1,
+            // This node has one output channel
+            port_types: &[0b10000000, 0, 0, 0],
+            // Report that data is being output correctly
+            good_output_a: &[0b10000000, 0, 0, 0],
+            ..Default::default()
+        }
+        .with_style(Style::Node)
+        .with_indicator_state(IndicatorState::Normal),
+    );
+
     // Receives a single datagram message on the socket. If `buf` is too small to hold
     // the message, it will be cut off.
     let mut buf = [0; 65_507];
     use tiny_artnet::Art;
 
+    // Nodes announce themselves by broadcasting an ArtPollReply when they start up
+    let msg_len = node.startup_reply(&mut buf[..]);
+    match tiny_artnet::broadcast_reply(&socket, &buf[..msg_len]) {
+        Ok(_) => println!("TX: Broadcast startup ArtPollReply"),
+        Err(err) => println!("Unable to broadcast the startup ArtPollReply: {}", err),
+    }
+
     loop {
         let (len, from_addr) = socket.recv_from(&mut buf).unwrap();
 
@@ -48,38 +78,37 @@ fn main() {
             Ok(Art::Poll(poll)) => {
                 println!("RX: ArtPoll - Someone is looking for ArtNet nodes. Let's respond to them to make this node discoverable! {:?}", poll);
 
-                let poll_reply = tiny_artnet::PollReply {
-                    ip_address: &ip_address,
-                    port,
-                    firmware_version: 0x0001,
-                    short_name: "Example Node",
-                    long_name: "Tiny Artnet Example Node",
-                    mac_address: &mac_address_bytes,
-                    // This Node has one port
-                    num_ports: 1,
-                    // This node has one output channel
-                    port_types: &[0b10000000, 0, 0, 0],
-                    // Report that data is being output correctly
-                    good_output_a: &[0b10000000, 0, 0, 0],
-                    ..Default::default()
+                // Targeted polls are only answered by nodes with a Port-Address in the target range
+                if !node.should_reply(&poll) {
+                    continue;
+                }
+
+                let msg_len = node.reply.serialize(&mut buf[..]);
+
+                let from_ip = match from_addr.ip() {
+                    IpAddr::V4(ip) => ip.octets(),
+                    IpAddr::V6(_) => [0; 4],
                 };
 
-                let msg_len = poll_reply.serialize(&mut buf);
-                socket.send_to(&buf[..msg_len], &from_addr).unwrap();
-                // let broadcast: UdpSocket = UdpSocket::bind("0.0.0.0:0").unwrap();
-                // broadcast
-                //     .set_read_timeout(Some(Duration::new(5, 0)))
-                //     .unwrap();
-                // broadcast.set_broadcast(true).unwrap();
-                // broadcast
-                //     .send_to(&buf[..msg_len], "255.255.255.255")
-                //     .unwrap();
-
-                println!("TX: Sent ArtPollReply to {:?}: {:?}", from_addr, poll_reply);
+                match tiny_artnet::reply_destination(&poll, from_ip) {
+                    ReplyDestination::Unicast(ip) => {
+                        socket
+                            .send_to(&buf[..msg_len], (Ipv4Addr::from(ip), tiny_artnet::PORT))
+                            .unwrap();
+                    }
+                    ReplyDestination::Broadcast => {
+                        tiny_artnet::broadcast_reply(&socket, &buf[..msg_len]).unwrap();
+                    }
+                }
+
+                println!("TX: Sent ArtPollReply to {:?}: {:?}", from_addr, node.reply);
             }
+            // Other applications may share the port, silently drop their datagrams
+            Err(tiny_artnet::Error::BadId) => {}
             Err(err) => {
                 println!("Error: {:?}", err);
             }
+            // Art is non-exhaustive so a wildcard arm is required to handle op-codes added in future versions
             msg => {
                 println!("Something else! {:?}", msg);
             }
@@ -0,0 +1,91 @@
+use std::net::{Ipv4Addr, UdpSocket};
+use std::time::{Duration, Instant};
+
+use tiny_artnet::{Art, PortAddress};
+
+/// The universes this receiver outputs. A fixed table avoids any allocation or hashing in the receive loop.
+const PORT_TABLE: [PortAddress; 2] = [
+    PortAddress {
+        net: 0,
+        sub_net: 0,
+        universe: 0,
+    },
+    PortAddress {
+        net: 0,
+        sub_net: 0,
+        universe: 1,
+    },
+];
+
+/// A receive loop for nodes that only care about DMX, eg. when a controller is streaming many universes at a
+/// high frame rate.
+///
+/// Compared to the naive loop in the `artnet_node` example this:
+/// - uses `from_slice_dmx_only` so packets other than ArtPoll, ArtDmx and ArtSync are dropped after reading just
+///   the header instead of being fully parsed
+/// - reuses a single receive buffer and copies DMX data into fixed per-port buffers, so nothing is allocated
+///   per packet
+/// - only logs a summary once a second since printing every packet dominates the cost of parsing it
+///
+/// Run it with `cargo run --release --example dmx_receiver` and compare the packets per second it reports against
+/// the `artnet_node` example under the same load.
+fn main() {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, tiny_artnet::PORT)).unwrap();
+    // Wake up periodically so the stats are printed even when no packets are arriving
+    socket
+        .set_read_timeout(Some(Duration::from_millis(100)))
+        .unwrap();
+
+    println!("Listening for ArtDmx on port {}", tiny_artnet::PORT);
+
+    let mut buf = [0; 1_024];
+    let mut outputs = [[0u8; 512]; PORT_TABLE.len()];
+
+    let mut received = 0u32;
+    let mut applied = 0u32;
+    let mut frames = 0u32;
+    let mut stats_since = Instant::now();
+
+    loop {
+        // A timeout is treated as an empty read
+        let len = socket.recv(&mut buf).unwrap_or_default();
+
+        if len > 0 {
+            received += 1;
+
+            match tiny_artnet::from_slice_dmx_only(&buf[..len]) {
+                Ok(Some(Art::Dmx(dmx))) => {
+                    if let Some(port) = PORT_TABLE
+                        .iter()
+                        .position(|port_address| *port_address == dmx.port_address)
+                    {
+                        // Senders are not guaranteed to respect the 512 channel limit
+                        let len = dmx.data.len().min(512);
+                        outputs[port][..len].copy_from_slice(&dmx.data[..len]);
+                        applied += 1;
+                    }
+                }
+                Ok(Some(Art::Sync)) => {
+                    // Render `outputs` to the physical DMX ports here
+                    frames += 1;
+                }
+                // ArtPoll and other op-codes are ignored by this receiver
+                Ok(_) => {}
+                // Not an Art-Net packet or malformed
+                Err(_) => {}
+            }
+        }
+
+        if stats_since.elapsed() >= Duration::from_secs(1) {
+            println!(
+                "{} packets/s received, {} ArtDmx/s applied, {} ArtSync/s",
+                received, applied, frames
+            );
+
+            received = 0;
+            applied = 0;
+            frames = 0;
+            stats_since = Instant::now();
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use nom::number::complete as number;
+
+use crate::{parse_array, parse_padded_str};
+
+pub(crate) const OP_ADDRESS: u16 = 0x6000;
+
+/// Length of an ArtAddress packet, including the header
+pub(crate) const ADDRESS_LEN: usize = 107;
+
+/// ArtAddress is sent by a controller to remotely program a node's names, Port-Address and configuration.
+///
+/// Each programmable field is None when the controller leaves it unchanged.
+#[derive(Debug)]
+pub struct Address<'a> {
+    /// Bits 14-8 of the 15 bit Port-Address.
+    pub net_switch: Option<u8>,
+    /// The bind index of the node (or port) being programmed.
+    pub bind_index: u8,
+    /// Note: The spec specifies ASCII characters only
+    pub short_name: Option<&'a str>,
+    /// Note: The spec specifies ASCII characters only
+    pub long_name: Option<&'a str>,
+    /// Bits 3-0 of the 15 bit Port-Address for each input port.
+    pub swin: [Option<u8>; 4],
+    /// Bits 3-0 of the 15 bit Port-Address for each output port.
+    pub swout: [Option<u8>; 4],
+    /// Bits 7-4 of the 15 bit Port-Address.
+    pub sub_switch: Option<u8>,
+    /// The sACN priority (0-200) used when the node converts Art-Net to sACN.
+    pub acn_priority: Option<u8>,
+    pub command: AddressCommand,
+}
+
+/// The action an ArtAddress asks the node to perform.
+///
+/// Per-port commands carry the index (0-3) of the port they apply to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressCommand {
+    /// AcNone: No action.
+    None,
+    /// AcCancelMerge: The next ArtDmx packet cancels the node's merge mode.
+    CancelMerge,
+    /// AcLedNormal: The front panel indicators operate normally.
+    LedNormal,
+    /// AcLedMute: The front panel indicators are disabled and switched off.
+    LedMute,
+    /// AcLedLocate: Rapid flashing of the front panel indicators to identify the node.
+    LedLocate,
+    /// AcResetRxFlags: Resets the receive error flags reported in `good_input`.
+    ResetRxFlags,
+    /// AcAnalysisOn: Enables analysis and debugging mode.
+    AnalysisOn,
+    /// AcAnalysisOff: Disables analysis and debugging mode.
+    AnalysisOff,
+    /// AcFailHold: On network data loss the outputs hold their last state.
+    FailHold,
+    /// AcFailZero: On network data loss the outputs are set to zero.
+    FailZero,
+    /// AcFailFull: On network data loss the outputs are set to full.
+    FailFull,
+    /// AcFailScene: On network data loss the outputs play back the recorded failsafe scene.
+    FailScene,
+    /// AcFailRecord: Records the current output state as the failsafe scene.
+    FailRecord,
+    /// AcMergeLtp: Sets the port to LTP merge mode.
+    MergeLtp(u8),
+    /// AcDirectionTx: Sets the port to output DMX from Art-Net.
+    DirectionTx(u8),
+    /// AcDirectionRx: Sets the port to input DMX to Art-Net.
+    DirectionRx(u8),
+    /// AcMergeHtp: Sets the port to HTP merge mode (the default).
+    MergeHtp(u8),
+    /// AcArtNetSel: Sets the port to output Art-Net protocol.
+    ArtNetSelect(u8),
+    /// AcAcnSel: Sets the port to output sACN protocol.
+    AcnSelect(u8),
+    /// AcClearOp: Clears the port's output buffer.
+    ClearOutput(u8),
+    /// AcStyleDelta: Sets the port's output style to delta, only changed channels are output.
+    StyleDelta(u8),
+    /// AcStyleConst: Sets the port's output style to constant, the output is refreshed continuously.
+    StyleConstant(u8),
+    /// AcRdmEnable: Enables RDM on the port.
+    RdmEnable(u8),
+    /// AcRdmDisable: Disables RDM on the port.
+    RdmDisable(u8),
+    /// AcBqp: Sets the background queue policy (0-15) used for background RDM discovery.
+    BackgroundQueuePolicy(u8),
+    /// A command not defined by the spec
+    Unknown(u8),
+}
+
+impl AddressCommand {
+    pub fn from_u8(command: u8) -> AddressCommand {
+        let port = command & 0x03;
+
+        match command {
+            0x00 => AddressCommand::None,
+            0x01 => AddressCommand::CancelMerge,
+            0x02 => AddressCommand::LedNormal,
+            0x03 => AddressCommand::LedMute,
+            0x04 => AddressCommand::LedLocate,
+            0x05 => AddressCommand::ResetRxFlags,
+            0x06 => AddressCommand::AnalysisOn,
+            0x07 => AddressCommand::AnalysisOff,
+            0x08 => AddressCommand::FailHold,
+            0x09 => AddressCommand::FailZero,
+            0x0A => AddressCommand::FailFull,
+            0x0B => AddressCommand::FailScene,
+            0x0C => AddressCommand::FailRecord,
+            0x10..=0x13 => AddressCommand::MergeLtp(port),
+            0x20..=0x23 => AddressCommand::DirectionTx(port),
+            0x30..=0x33 => AddressCommand::DirectionRx(port),
+            0x50..=0x53 => AddressCommand::MergeHtp(port),
+            0x60..=0x63 => AddressCommand::ArtNetSelect(port),
+            0x70..=0x73 => AddressCommand::AcnSelect(port),
+            0x90..=0x93 => AddressCommand::ClearOutput(port),
+            0xA0..=0xA3 => AddressCommand::StyleDelta(port),
+            0xB0..=0xB3 => AddressCommand::StyleConstant(port),
+            0xC0..=0xC3 => AddressCommand::RdmEnable(port),
+            0xD0..=0xD3 => AddressCommand::RdmDisable(port),
+            0xE0..=0xEF => AddressCommand::BackgroundQueuePolicy(command & 0x0F),
+            _ => AddressCommand::Unknown(command),
+        }
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        match *self {
+            AddressCommand::None => 0x00,
+            AddressCommand::CancelMerge => 0x01,
+            AddressCommand::LedNormal => 0x02,
+            AddressCommand::LedMute => 0x03,
+            AddressCommand::LedLocate => 0x04,
+            AddressCommand::ResetRxFlags => 0x05,
+            AddressCommand::AnalysisOn => 0x06,
+            AddressCommand::AnalysisOff => 0x07,
+            AddressCommand::FailHold => 0x08,
+            AddressCommand::FailZero => 0x09,
+            AddressCommand::FailFull => 0x0A,
+            AddressCommand::FailScene => 0x0B,
+            AddressCommand::FailRecord => 0x0C,
+            AddressCommand::MergeLtp(port) => 0x10 | (port & 0x03),
+            AddressCommand::DirectionTx(port) => 0x20 | (port & 0x03),
+            AddressCommand::DirectionRx(port) => 0x30 | (port & 0x03),
+            AddressCommand::MergeHtp(port) => 0x50 | (port & 0x03),
+            AddressCommand::ArtNetSelect(port) => 0x60 | (port & 0x03),
+            AddressCommand::AcnSelect(port) => 0x70 | (port & 0x03),
+            AddressCommand::ClearOutput(port) => 0x90 | (port & 0x03),
+            AddressCommand::StyleDelta(port) => 0xA0 | (port & 0x03),
+            AddressCommand::StyleConstant(port) => 0xB0 | (port & 0x03),
+            AddressCommand::RdmEnable(port) => 0xC0 | (port & 0x03),
+            AddressCommand::RdmDisable(port) => 0xD0 | (port & 0x03),
+            AddressCommand::BackgroundQueuePolicy(policy) => 0xE0 | (policy & 0x0F),
+            AddressCommand::Unknown(command) => command,
+        }
+    }
+}
+
+/// Every command byte decodes to an AddressCommand (unknown commands are kept as `Unknown`)
+impl From<u8> for AddressCommand {
+    fn from(command: u8) -> Self {
+        AddressCommand::from_u8(command)
+    }
+}
+
+/// Decodes a switch field which is only programmed when bit 7 is set.
+///
+/// The spec uses 0x7F to mean "no change" and 0x00 to reset the switch to the node's physical switch settings.
+/// Both are decoded as None since neither programs a new value.
+fn parse_switch(switch: u8) -> Option<u8> {
+    if switch & 0x80 != 0 {
+        Some(switch & 0x7F)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn parse_address<'a>(s: &'a [u8]) -> Result<Address<'a>, crate::Error<'a>> {
+    let (s, net_switch) = number::u8(s)?;
+    let (s, bind_index) = number::u8(s)?;
+    // Null strings mean "no change"
+    let (s, short_name) = parse_padded_str::<18>(s)?;
+    let (s, long_name) = parse_padded_str::<64>(s)?;
+    let (s, swin) = parse_array::<4>(s)?;
+    let (s, swout) = parse_array::<4>(s)?;
+    let (s, sub_switch) = number::u8(s)?;
+    let (s, acn_priority) = number::u8(s)?;
+    let (_s, command) = number::u8(s)?;
+
+    Ok(Address {
+        net_switch: parse_switch(net_switch),
+        bind_index,
+        short_name: Some(short_name).filter(|name| !name.is_empty()),
+        long_name: Some(long_name).filter(|name| !name.is_empty()),
+        swin: swin.map(parse_switch),
+        swout: swout.map(parse_switch),
+        sub_switch: parse_switch(sub_switch),
+        // 255 means "no change"
+        acn_priority: Some(acn_priority).filter(|priority| *priority != 255),
+        command: AddressCommand::from_u8(command),
+    })
+}
@@ -0,0 +1,11 @@
+use std::{
+    io,
+    net::{Ipv4Addr, UdpSocket},
+};
+
+/// Broadcasts a serialized ArtPollReply to the limited broadcast address (255.255.255.255) on the Art-Net `PORT`,
+/// enabling `SO_BROADCAST` on the socket first.
+pub fn broadcast_reply(socket: &UdpSocket, reply_bytes: &[u8]) -> io::Result<usize> {
+    socket.set_broadcast(true)?;
+    socket.send_to(reply_bytes, (Ipv4Addr::BROADCAST, crate::PORT))
+}
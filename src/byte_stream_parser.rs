@@ -0,0 +1,182 @@
+use crate::{
+    from_slice, packet_len_for_op_code, peek_op_code, poll_reply::OP_POLL_REPLY, Art, Error, ID,
+    OP_POLL,
+};
+
+/// Assembles Art-Net packets from a transport that delivers them a byte at a time, eg. a gateway tunneling Art-Net
+/// over a UART, using `packet_len` to find where each packet ends.
+///
+/// Packets are buffered in an internal N byte array so no allocation is needed. Bytes that do not begin with the
+/// "Art-Net" ID are skipped to resynchronize after a corrupted or partial packet, as are packets longer than N bytes.
+///
+/// ArtPoll and ArtPollReply packets from older senders may be shorter than their assumed length, so they also end
+/// where the next packet's ID begins. A short packet is only returned once that ID has been received.
+#[derive(Debug, Clone)]
+pub struct ByteStreamParser<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    /// The length of the packet being skipped because it does not fit in the buffer
+    skip: usize,
+    /// Set when the buffer ends with the ID of the next packet, which is moved to the front on the next push
+    carry_id: bool,
+}
+
+impl<const N: usize> Default for ByteStreamParser<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> ByteStreamParser<N> {
+    pub fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+            skip: 0,
+            carry_id: false,
+        }
+    }
+
+    /// Discards any partially received packet.
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.skip = 0;
+        self.carry_id = false;
+    }
+
+    /// Adds the next byte from the stream, returning the parsed packet once all of its bytes have been received.
+    ///
+    /// Packets with op-codes this crate does not parse return `Error::UnsupportedOpCode` (their length is unknown so
+    /// the parser resynchronizes on the next ID).
+    pub fn push(&mut self, byte: u8) -> Option<Result<Art<'_>, Error<'_>>> {
+        if self.carry_id {
+            self.buf.copy_within(self.len - ID.len()..self.len, 0);
+            self.len = ID.len();
+            self.carry_id = false;
+        }
+
+        if self.skip > 0 {
+            self.skip -= 1;
+            return None;
+        }
+
+        // Resynchronize on the ID
+        if self.len < ID.len() && byte != ID[self.len] {
+            self.len = 0;
+
+            if byte != ID[0] {
+                return None;
+            }
+        }
+
+        if self.len == N {
+            // The buffer is too small to hold the fields the packet's length depends on
+            self.len = 0;
+            return None;
+        }
+
+        self.buf[self.len] = byte;
+        self.len += 1;
+
+        let len = self.len;
+        let packet = &self.buf[..len];
+
+        let variable_len = matches!(peek_op_code(packet), Some(OP_POLL | OP_POLL_REPLY));
+        if variable_len && len > ID.len() && packet.ends_with(ID) {
+            // The next packet began before the assumed end of this one
+            self.carry_id = true;
+            return Some(from_slice(&self.buf[..len - ID.len()]));
+        }
+
+        match packet_len_for_op_code(packet) {
+            Some(Some(packet_len)) if packet_len > N => {
+                // Skip the rest of a packet that does not fit in the buffer
+                self.skip = packet_len - len;
+                self.len = 0;
+                None
+            }
+            // Wait for the rest of the ID if the trailing bytes may be the start of the next packet
+            Some(Some(packet_len))
+                if variable_len && packet_len <= len && len < N && may_begin_id(packet) =>
+            {
+                None
+            }
+            Some(Some(packet_len)) if packet_len <= len => {
+                self.len = 0;
+                // Any bytes beyond the assumed length of an ArtPoll or ArtPollReply are ignored by the parser
+                Some(from_slice(&self.buf[..len]))
+            }
+            // Waiting for more bytes
+            Some(_) => None,
+            None => {
+                // Waiting for the op-code, otherwise the op-code is unsupported
+                let op_code = peek_op_code(packet)?;
+                self.len = 0;
+                Some(Err(Error::UnsupportedOpCode(op_code)))
+            }
+        }
+    }
+}
+
+// True if the bytes at the end of the packet (after the ID and op-code) are a prefix of the ID
+fn may_begin_id(packet: &[u8]) -> bool {
+    (ID.len() + 2..packet.len()).any(|offset| ID.starts_with(&packet[offset..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{serialize_sync, Dmx, Poll, PortAddress};
+
+    #[test]
+    fn dmx_fed_a_byte_at_a_time() {
+        let data = [255, 0, 128, 64];
+        let mut buf = [0u8; 64];
+        let len =
+            Dmx::new(PortAddress::from_index(17).unwrap(), &data).serialize(&mut buf[..], false);
+
+        let mut parser = ByteStreamParser::<64>::new();
+        for byte in &buf[..len - 1] {
+            assert!(parser.push(*byte).is_none());
+        }
+
+        match parser.push(buf[len - 1]) {
+            Some(Ok(Art::Dmx(dmx))) => {
+                assert_eq!(dmx.universe(), 17);
+                assert_eq!(dmx.data, &data);
+            }
+            msg => panic!("Expected an ArtDmx but received: {:?}", msg),
+        };
+    }
+
+    #[test]
+    fn short_polls_end_at_the_next_id() {
+        // A 14 byte Art-Net 3 poll, an 18 byte poll without the manufacturer filters and a sync
+        let mut buf = [0u8; 64];
+        buf[..ID.len()].copy_from_slice(ID);
+        buf[8..14].copy_from_slice(&[0x00, 0x20, 0, 14, 0b0000_0010, 0]);
+        let mut len = 14;
+        len += Poll::default().serialize(&mut buf[len..]);
+        len += serialize_sync(&mut buf[len..]);
+
+        let mut parser = ByteStreamParser::<64>::new();
+        let mut polls = 0;
+        let mut syncs = 0;
+
+        for byte in &buf[..len] {
+            match parser.push(*byte) {
+                None => {}
+                Some(Ok(Art::Poll(poll))) => {
+                    assert_eq!(poll.flags, if polls == 0 { 0b0000_0010 } else { 0 });
+                    assert_eq!(poll.target_port_addresses, 0..=u16::MAX);
+                    assert_eq!(poll.esta_filter, None);
+                    polls += 1;
+                }
+                Some(Ok(Art::Sync)) => syncs += 1,
+                msg => panic!("Unexpected packet: {:?}", msg),
+            }
+        }
+
+        assert_eq!((polls, syncs), (2, 1));
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{bounded_packet_len, find_next_packet, Art};
+
+/// A buffer holding one or more concatenated Art-Net packets, eg. a capture payload.
+///
+/// Iterating over it (eg. `for art in ArtNetDatagram(&buf[..len])`) yields each parseable packet in order, using
+/// `find_next_packet` to skip junk and corrupted packets.
+#[derive(Debug, Clone, Copy)]
+pub struct ArtNetDatagram<'a>(pub &'a [u8]);
+
+impl<'a> IntoIterator for ArtNetDatagram<'a> {
+    type Item = Art<'a>;
+    type IntoIter = Packets<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Packets { remaining: self.0 }
+    }
+}
+
+/// Iterator over the packets in an `ArtNetDatagram`
+#[derive(Debug, Clone)]
+pub struct Packets<'a> {
+    remaining: &'a [u8],
+}
+
+impl<'a> Iterator for Packets<'a> {
+    type Item = Art<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some((offset, art)) = find_next_packet(self.remaining) else {
+            self.remaining = &[];
+            return None;
+        };
+
+        // Continue after the end of this packet so that its payload is never scanned for the next ID
+        let packet = &self.remaining[offset..];
+        self.remaining = &packet[bounded_packet_len(packet)..];
+
+        Some(art)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{serialize_sync, Dmx, Poll, PortAddress};
+
+    #[test]
+    fn iterates_concatenated_packets() {
+        let mut buf = [0u8; 128];
+        let mut len = Poll::default().serialize(&mut buf[..]);
+        // DMX data that happens to contain the ID must not be mistaken for the start of a packet
+        let data = *b"..Art-Net\0..";
+        len +=
+            Dmx::new(PortAddress::from_index(3).unwrap(), &data).serialize(&mut buf[len..], false);
+        len += serialize_sync(&mut buf[len..]);
+
+        let mut packets = ArtNetDatagram(&buf[..len]).into_iter();
+
+        match packets.next() {
+            Some(Art::Poll(poll)) => assert_eq!(poll.target_port_addresses, 0..=u16::MAX),
+            msg => panic!("Expected an ArtPoll but found: {:?}", msg),
+        };
+        match packets.next() {
+            Some(Art::Dmx(dmx)) => {
+                assert_eq!(dmx.universe(), 3);
+                assert_eq!(&dmx.data[..data.len()], &data);
+            }
+            msg => panic!("Expected an ArtDmx but found: {:?}", msg),
+        };
+        match packets.next() {
+            Some(Art::Sync) => {}
+            msg => panic!("Expected an ArtSync but found: {:?}", msg),
+        };
+        assert!(packets.next().is_none());
+    }
+
+    #[test]
+    fn short_poll_does_not_swallow_the_next_packet() {
+        // A 14 byte Art-Net 3 poll without the targeting fields followed by an ArtSync
+        let mut buf = [0u8; 32];
+        buf[..8].copy_from_slice(b"Art-Net\0");
+        buf[8..14].copy_from_slice(&[0x00, 0x20, 0, 14, 0, 0]);
+        let len = 14 + serialize_sync(&mut buf[14..]);
+
+        let mut packets = ArtNetDatagram(&buf[..len]).into_iter();
+
+        match packets.next() {
+            Some(Art::Poll(poll)) => assert_eq!(poll.target_port_addresses, 0..=u16::MAX),
+            msg => panic!("Expected an ArtPoll but found: {:?}", msg),
+        };
+        assert!(matches!(packets.next(), Some(Art::Sync)));
+        assert!(packets.next().is_none());
+    }
+}
@@ -0,0 +1,127 @@
+use nom::{bytes::complete::take, combinator::map_opt, number::complete as number};
+
+use crate::{parse_array, put_header, sink::Counter, ByteSink, SerializeError};
+
+pub(crate) const OP_DIAG_DATA: u16 = 0x2300;
+
+/// Length of an ArtDiagData packet excluding the message
+pub(crate) const DIAG_DATA_HEADER_LEN: usize = 18;
+
+/// The severity of a diagnostic message. ArtPoll's `min_diagnostic_priority` requests messages of at least this
+/// priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum DiagnosticPriority {
+    /// DpLow: Low priority message.
+    Low = 0x10,
+    /// DpMed: Medium priority message.
+    Medium = 0x40,
+    /// DpHigh: High priority message.
+    High = 0x80,
+    /// DpCritical: Critical priority message.
+    Critical = 0xE0,
+    /// DpVolatile: Volatile message. Messages of this type are displayed on a single line in the DMX-Workshop
+    /// diagnostics display. All other types are displayed in a list box.
+    Volatile = 0xF0,
+}
+
+impl DiagnosticPriority {
+    pub fn from_u8(priority: u8) -> Option<DiagnosticPriority> {
+        let priority = match priority {
+            0x10 => DiagnosticPriority::Low,
+            0x40 => DiagnosticPriority::Medium,
+            0x80 => DiagnosticPriority::High,
+            0xE0 => DiagnosticPriority::Critical,
+            0xF0 => DiagnosticPriority::Volatile,
+            _ => return None,
+        };
+
+        Some(priority)
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl TryFrom<u8> for DiagnosticPriority {
+    /// The unknown priority
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        DiagnosticPriority::from_u8(value).ok_or(value)
+    }
+}
+
+/// ArtDiagData is a general purpose packet that allows a node or controller to send diagnostics data for display.
+#[derive(Debug)]
+pub struct DiagData<'a> {
+    pub priority: DiagnosticPriority,
+    /// The logical DMX port of the product to which the message relates. Zero is used for general messages.
+    pub logical_port: u8,
+    /// Note: The spec specifies ASCII characters only
+    pub message: &'a str,
+}
+
+pub(crate) fn parse_diag_data<'a>(s: &'a [u8]) -> Result<DiagData<'a>, crate::Error<'a>> {
+    // Filler
+    let (s, _) = parse_array::<1>(s)?;
+    let (s, priority) = map_opt(number::u8, DiagnosticPriority::from_u8)(s)?;
+    let (s, logical_port) = number::u8(s)?;
+    // Filler
+    let (s, _) = parse_array::<1>(s)?;
+    let (s, length) = crate::read_dmx_length(s)?;
+    let (_s, data) = take(length)(s)?;
+
+    // The message is Nul terminated
+    let len = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+    let message = core::str::from_utf8(&data[..len])
+        .map_err(|_| nom::Err::Error(nom::error::Error::new(data, nom::error::ErrorKind::Char)))?;
+
+    Ok(DiagData {
+        priority,
+        logical_port,
+        message,
+    })
+}
+
+impl<'a> DiagData<'a> {
+    pub fn new(priority: DiagnosticPriority, logical_port: u8, message: &'a str) -> Self {
+        Self {
+            priority,
+            logical_port,
+            message,
+        }
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        // The message is followed by a Nul terminator
+        DIAG_DATA_HEADER_LEN + self.message.len() + 1
+    }
+
+    /// Serializes the ArtDiagData into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the ArtDiagData into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+
+        put_header(&mut buf, OP_DIAG_DATA);
+        // Filler
+        buf.put_u8(0);
+        buf.put_u8(self.priority.as_u8());
+        buf.put_u8(self.logical_port);
+        // Filler
+        buf.put_u8(0);
+        buf.put_u16((self.message.len() + 1) as u16);
+        buf.put_slice(self.message.as_bytes());
+        buf.put_u8(0);
+
+        buf.written()
+    }
+}
@@ -0,0 +1,136 @@
+use nom::number::complete::{self as number, be_u16, be_u64};
+
+use crate::{
+    parse_array, parse_padded_str, put_header, put_padded_str, sink::Counter, ByteSink,
+    SerializeError,
+};
+
+pub(crate) const OP_DIRECTORY: u16 = 0x9A00;
+pub(crate) const OP_DIRECTORY_REPLY: u16 = 0x9B00;
+
+/// Length of a serialized ArtDirectory, including the header
+pub(crate) const DIRECTORY_LEN: usize = 17;
+/// Length of a serialized ArtDirectoryReply, including the header
+pub(crate) const DIRECTORY_REPLY_LEN: usize = 169;
+
+/// ArtDirectory is sent by a media management tool to request a node's file list.
+#[derive(Debug)]
+pub struct Directory {
+    /// The raw command byte
+    pub command: u8,
+    /// The file number the listing should start from
+    pub file: u16,
+}
+
+pub(crate) fn parse_directory<'a>(s: &'a [u8]) -> Result<Directory, crate::Error<'a>> {
+    // Filler
+    let (s, _) = parse_array::<2>(s)?;
+    let (s, command) = number::u8(s)?;
+    let (_s, file) = be_u16(s)?;
+
+    Ok(Directory { command, file })
+}
+
+impl Directory {
+    pub fn new(command: u8, file: u16) -> Self {
+        Self { command, file }
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        DIRECTORY_LEN
+    }
+
+    /// Serializes the ArtDirectory into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the ArtDirectory into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+
+        put_header(&mut buf, OP_DIRECTORY);
+        // Filler
+        buf.put_slice(&[0u8; 2]);
+        buf.put_u8(self.command);
+        buf.put_u16(self.file);
+
+        buf.written()
+    }
+}
+
+/// ArtDirectoryReply is sent by a node to describe one of its files in answer to an ArtDirectory.
+#[derive(Debug)]
+pub struct DirectoryReply<'a> {
+    /// The raw flags byte
+    pub flags: u8,
+    /// The file number this reply describes
+    pub file: u16,
+    /// The 8.3 file name. Note: The spec specifies ASCII characters only
+    pub name: &'a str,
+    /// Note: The spec specifies ASCII characters only
+    pub description: &'a str,
+    /// The file's length in bytes
+    pub length: u64,
+    /// Application specific data
+    pub data: &'a [u8; 64],
+}
+
+pub(crate) fn parse_directory_reply<'a>(
+    s: &'a [u8],
+) -> Result<DirectoryReply<'a>, crate::Error<'a>> {
+    // Filler
+    let (s, _) = parse_array::<2>(s)?;
+    let (s, flags) = number::u8(s)?;
+    let (s, file) = be_u16(s)?;
+    let (s, name) = parse_padded_str::<16>(s)?;
+    let (s, description) = parse_padded_str::<64>(s)?;
+    let (s, length) = be_u64(s)?;
+    let (_s, data) = parse_array::<64>(s)?;
+
+    Ok(DirectoryReply {
+        flags,
+        file,
+        name,
+        description,
+        length,
+        data,
+    })
+}
+
+impl<'a> DirectoryReply<'a> {
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        DIRECTORY_REPLY_LEN
+    }
+
+    /// Serializes the ArtDirectoryReply into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the ArtDirectoryReply into the provided sink (eg. a `&mut [u8]`), returning the number of bytes
+    /// written.
+    ///
+    /// Note: name and description will be truncated to 16 and 64 bytes respectively
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+
+        put_header(&mut buf, OP_DIRECTORY_REPLY);
+        // Filler
+        buf.put_slice(&[0u8; 2]);
+        buf.put_u8(self.flags);
+        buf.put_u16(self.file);
+        put_padded_str::<16, _>(&mut buf, self.name);
+        put_padded_str::<64, _>(&mut buf, self.description);
+        buf.put_slice(&self.length.to_be_bytes());
+        buf.put_slice(self.data);
+
+        buf.written()
+    }
+}
@@ -0,0 +1,46 @@
+use alloc::vec::Vec;
+
+use crate::PollReply;
+
+/// A physical device assembled from the ArtPollReplies it sent.
+///
+/// Nodes with more than 4 ports send one reply per group of ports, each with a different `bind_index`.
+#[derive(Debug)]
+pub struct DeviceGroup<'r, 'a> {
+    pub mac_address: [u8; 6],
+    /// The IP address of the root device
+    pub bind_ip_address: [u8; 4],
+    /// The device's replies ordered by `bind_index`
+    pub replies: Vec<&'r PollReply<'a>>,
+}
+
+/// Groups discovery results by physical device (see `PollReply::device_key`), preserving the order in which each
+/// device was first seen.
+pub fn group_replies<'r, 'a>(
+    replies: &'r [PollReply<'a>],
+) -> impl Iterator<Item = DeviceGroup<'r, 'a>> {
+    let mut groups: Vec<DeviceGroup<'r, 'a>> = Vec::new();
+
+    for reply in replies {
+        let (mac_address, bind_ip_address) = reply.device_key();
+
+        let group = groups.iter_mut().find(|group| {
+            group.mac_address == mac_address && group.bind_ip_address == bind_ip_address
+        });
+
+        match group {
+            Some(group) => group.replies.push(reply),
+            None => groups.push(DeviceGroup {
+                mac_address,
+                bind_ip_address,
+                replies: alloc::vec![reply],
+            }),
+        }
+    }
+
+    for group in groups.iter_mut() {
+        group.replies.sort_by_key(|reply| reply.bind_index);
+    }
+
+    groups.into_iter()
+}
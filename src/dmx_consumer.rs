@@ -0,0 +1,25 @@
+use crate::{Art, PortAddress};
+
+/// The integration point between parsed packets and a lighting engine's channel map. See `Art::feed`.
+pub trait DmxConsumer {
+    /// Applies a universe of DMX channel data received for the Port-Address.
+    fn apply(&mut self, port_address: &PortAddress, data: &[u8]);
+
+    /// Applies a universe of non-zero start code data (ArtNzs) received for the Port-Address. This is kept separate
+    /// from `apply` since the data is not channel levels, the default implementation ignores it.
+    fn apply_nzs(&mut self, port_address: &PortAddress, start_code: u8, data: &[u8]) {
+        let _ = (port_address, start_code, data);
+    }
+}
+
+impl<'a> Art<'a> {
+    /// Dispatches the DMX (ArtDmx) or non-zero start code (ArtNzs) data carried by the packet into the consumer.
+    /// Other packets are ignored.
+    pub fn feed<C: DmxConsumer + ?Sized>(&self, consumer: &mut C) {
+        match self {
+            Art::Dmx(dmx) => consumer.apply(&dmx.port_address, dmx.data),
+            Art::Nzs(nzs) => consumer.apply_nzs(&nzs.port_address, nzs.start_code, nzs.data),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Readers for the 16-bit fields shared by many op-codes.
+//!
+//! Art-Net mixes byte orders: the op-code, the ArtPollReply port and the ArtCommand length are little endian whereas
+//! the protocol version, the ArtDmx (and ArtDiagData) length and the ArtPollReply port count are big endian. Custom
+//! op-code parsers should use these rather than picking `le_u16` / `be_u16` by hand.
+
+use nom::{
+    number::complete::{be_u16, le_u16},
+    IResult,
+};
+
+/// Reads an op-code, sent little endian (Lo byte first).
+pub fn read_artnet_opcode(s: &[u8]) -> IResult<&[u8], u16> {
+    le_u16(s)
+}
+
+/// Reads a protocol version, sent big endian (Hi byte first).
+pub fn read_protocol_version(s: &[u8]) -> IResult<&[u8], u16> {
+    be_u16(s)
+}
+
+/// Reads the data length of an ArtDmx, ArtNzs or ArtDiagData, sent big endian (Hi byte first).
+pub fn read_dmx_length(s: &[u8]) -> IResult<&[u8], u16> {
+    be_u16(s)
+}
+
+/// Reads the data length of an ArtCommand, which unlike the other lengths is sent little endian (Lo byte first).
+pub fn read_command_length(s: &[u8]) -> IResult<&[u8], u16> {
+    le_u16(s)
+}
+
+/// Reads the UDP port of an ArtPollReply, sent little endian (Lo byte first).
+pub fn read_udp_port(s: &[u8]) -> IResult<&[u8], u16> {
+    le_u16(s)
+}
@@ -0,0 +1,33 @@
+use core::ops::RangeInclusive;
+
+use crate::{Art, OpCode};
+
+/// Selects packets from a stream, eg. for capture and analysis tools. See `Art::matches`.
+#[derive(Debug, Default, Clone)]
+pub struct Filter<'f> {
+    /// Only match packets with one of these op-codes. None matches every op-code.
+    pub op_codes: Option<&'f [OpCode]>,
+    /// Only match packets addressed to a Port-Address (see `PortAddress::as_index`) in this range. Packets without a
+    /// Port-Address never match. None matches every packet.
+    pub port_addresses: Option<RangeInclusive<u16>>,
+}
+
+impl<'a> Art<'a> {
+    /// Returns true if the packet is selected by the filter
+    pub fn matches(&self, filter: &Filter) -> bool {
+        if let Some(op_codes) = filter.op_codes {
+            if !op_codes.contains(&self.op_code()) {
+                return false;
+            }
+        }
+
+        if let Some(port_addresses) = &filter.port_addresses {
+            return match self.port_address() {
+                Some(port_address) => port_addresses.contains(&(port_address.as_index() as u16)),
+                None => false,
+            };
+        }
+
+        true
+    }
+}
@@ -0,0 +1,88 @@
+use nom::{combinator::map_opt, number::complete as number};
+
+use crate::{parse_array, put_header, sink::Counter, ByteSink, SerializeError};
+
+pub(crate) const OP_FIRMWARE_REPLY: u16 = 0xF300;
+
+/// Length of a serialized ArtFirmwareReply, including the header
+pub(crate) const FIRMWARE_REPLY_LEN: usize = 36;
+
+/// The status a node reports in an ArtFirmwareReply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FirmwareReplyType {
+    /// FirmBlockGood: The last packet was received successfully.
+    BlockGood = 0x00,
+    /// FirmAllGood: All firmware was received successfully.
+    AllGood = 0x01,
+    /// FirmFail: The firmware upload failed.
+    Fail = 0xFF,
+}
+
+impl FirmwareReplyType {
+    pub fn from_u8(reply_type: u8) -> Option<FirmwareReplyType> {
+        let reply_type = match reply_type {
+            0x00 => FirmwareReplyType::BlockGood,
+            0x01 => FirmwareReplyType::AllGood,
+            0xFF => FirmwareReplyType::Fail,
+            _ => return None,
+        };
+
+        Some(reply_type)
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl TryFrom<u8> for FirmwareReplyType {
+    /// The unknown reply type
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        FirmwareReplyType::from_u8(value).ok_or(value)
+    }
+}
+
+/// ArtFirmwareReply is sent by a node to acknowledge each block of an ArtFirmwareMaster firmware upload.
+#[derive(Debug)]
+pub struct FirmwareReply {
+    pub reply_type: FirmwareReplyType,
+}
+
+pub(crate) fn parse_firmware_reply<'a>(s: &'a [u8]) -> Result<FirmwareReply, crate::Error<'a>> {
+    let (s, _filler) = parse_array::<2>(s)?;
+    let (_s, reply_type) = map_opt(number::u8, FirmwareReplyType::from_u8)(s)?;
+
+    Ok(FirmwareReply { reply_type })
+}
+
+impl FirmwareReply {
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        FIRMWARE_REPLY_LEN
+    }
+
+    /// Serializes the ArtFirmwareReply into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the ArtFirmwareReply into the provided sink (eg. a `&mut [u8]`), returning the number of bytes
+    /// written.
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+
+        put_header(&mut buf, OP_FIRMWARE_REPLY);
+        // Filler
+        buf.put_slice(&[0u8; 2]);
+        buf.put_u8(self.reply_type.as_u8());
+        // Spare
+        buf.put_slice(&[0u8; 21]);
+
+        buf.written()
+    }
+}
@@ -0,0 +1,97 @@
+use nom::number::complete::{self as number, be_u16};
+
+use crate::{parse_array, put_header, sink::Counter, ByteSink, SerializeError};
+
+pub(crate) const OP_INPUT: u16 = 0x7000;
+
+/// Length of a serialized ArtInput, including the header
+pub(crate) const INPUT_LEN: usize = 20;
+
+/// The ArtPollReply `good_input` bit set when an input is disabled
+const GOOD_INPUT_DISABLED: u8 = 0b0000_1000;
+
+/// ArtInput is sent by a controller to enable or disable a node's DMX inputs.
+#[derive(Debug)]
+pub struct Input<'a> {
+    /// The bind index of the node (or port) the inputs belong to.
+    pub bind_index: u8,
+    /// The number of input ports. Sent as a big endian u16 with a maximum value of 4.
+    pub num_ports: u16,
+    /// One byte per input port. Bit 0 set disables the input.
+    pub input: &'a [u8; 4],
+}
+
+impl<'a> Default for Input<'a> {
+    fn default() -> Self {
+        Self {
+            bind_index: 0,
+            num_ports: 0,
+            input: crate::DEFAULT_4_BYTES,
+        }
+    }
+}
+
+pub(crate) fn parse_input<'a>(s: &'a [u8]) -> Result<Input<'a>, crate::Error<'a>> {
+    let (s, _filler) = number::u8(s)?;
+    let (s, bind_index) = number::u8(s)?;
+    let (s, num_ports) = be_u16(s)?;
+    let (_s, input) = parse_array::<4>(s)?;
+
+    Ok(Input {
+        bind_index,
+        num_ports,
+        input,
+    })
+}
+
+impl<'a> Input<'a> {
+    /// True if the ArtInput disables the input port (0-3)
+    pub fn is_disabled(&self, port: usize) -> bool {
+        port < (self.num_ports as usize).min(4) && self.input[port] & 0b0000_0001 != 0
+    }
+
+    /// Updates the "input disabled" bit (bit 3) of each port's `good_input` status to match the ArtInput, so the
+    /// node's next ArtPollReply advertises the change. Ports beyond `num_ports` are left untouched.
+    ///
+    /// Note: A PollReply borrows its `good_input` so this updates the node's own buffer which the reply is then built
+    /// from.
+    pub fn apply_to_good_input(&self, good_input: &mut [u8; 4]) {
+        for (port, status) in good_input
+            .iter_mut()
+            .enumerate()
+            .take((self.num_ports as usize).min(4))
+        {
+            if self.is_disabled(port) {
+                *status |= GOOD_INPUT_DISABLED;
+            } else {
+                *status &= !GOOD_INPUT_DISABLED;
+            }
+        }
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        INPUT_LEN
+    }
+
+    /// Serializes the ArtInput into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the ArtInput into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+
+        put_header(&mut buf, OP_INPUT);
+        // Filler
+        buf.put_u8(0);
+        buf.put_u8(self.bind_index);
+        buf.put_u16(self.num_ports);
+        buf.put_slice(self.input);
+
+        buf.written()
+    }
+}
@@ -1,81 +1,605 @@
 #![no_std]
-extern crate tiny_artnet_bytes_no_atomic as bytes;
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Returns `SerializeError::BufferTooSmall` from the enclosing `try_serialize` if the buffer cannot hold the `needed`
+/// number of bytes, so serializers never panic on a short buffer.
+macro_rules! ensure_capacity {
+    ($buf:expr, $needed:expr) => {{
+        let needed = $needed;
+
+        if $buf.len() < needed {
+            return Err(crate::SerializeError::BufferTooSmall { needed });
+        }
+    }};
+}
+
+mod address;
+pub use address::{Address, AddressCommand};
+
+#[cfg(feature = "std")]
+mod broadcast;
+#[cfg(feature = "std")]
+pub use broadcast::broadcast_reply;
+
+mod byte_stream_parser;
+pub use byte_stream_parser::ByteStreamParser;
+
+mod datagram;
+pub use datagram::{ArtNetDatagram, Packets};
+
+mod diag_data;
+pub use diag_data::{DiagData, DiagnosticPriority};
+
+#[cfg(feature = "alloc")]
+mod discovery;
+#[cfg(feature = "alloc")]
+pub use discovery::{group_replies, DeviceGroup};
+
+mod directory;
+pub use directory::{Directory, DirectoryReply};
+
+mod dmx_consumer;
+pub use dmx_consumer::DmxConsumer;
+
+mod fields;
+pub use fields::{
+    read_artnet_opcode, read_command_length, read_dmx_length, read_protocol_version, read_udp_port,
+};
+
+mod filter;
+pub use filter::Filter;
+
+mod firmware_reply;
+pub use firmware_reply::{FirmwareReply, FirmwareReplyType};
+
+mod input;
+pub use input::Input;
+
+mod name;
+pub use name::Name;
+
+mod node;
+pub use node::{reply_destination, reply_on_change, Node, ReplyDestination};
+
+mod node_report;
+pub use node_report::{NodeReport, NodeReportCode};
+
+mod nzs;
+pub use nzs::Nzs;
+
+mod op_code;
+pub use op_code::OpCode;
 
 mod poll_reply;
-pub use poll_reply::PollReply;
+pub use poll_reply::{
+    FirmwareVersion, GoodOutput, IndicatorState, MixedNetError, Oem, OutputStyle, PollReply, Style,
+};
+
+mod port_address_matcher;
+pub use port_address_matcher::PortAddressMatcher;
+
+mod rdm;
+pub use rdm::Rdm;
+
+mod rdm_sub;
+pub use rdm_sub::RdmSub;
+
+mod reply_scheduler;
+pub use reply_scheduler::ReplyScheduler;
+
+mod sequence_monitor;
+pub use sequence_monitor::SequenceMonitor;
+
+mod sink;
+pub use sink::ByteSink;
+
+mod tod_control;
+pub use tod_control::{TodControl, TodControlCommand};
+
+mod tod_data;
+pub use tod_data::TodData;
+
+mod tod_request;
+pub use tod_request::TodRequest;
+
+mod universe_data;
+pub use universe_data::UniverseData;
 
 use core::ops::RangeInclusive;
 
-use bytes::BufMut;
+use sink::Counter;
+
 use nom::{
-    bytes::complete::tag,
-    number::complete as number,
-    number::complete::{be_u16, le_u16},
-    sequence::tuple,
-    IResult,
+    bytes::complete::take, combinator::opt, number::complete as number, number::complete::be_u16,
+    sequence::tuple, IResult,
 };
 
-const ID: &'static [u8] = b"Art-Net\0";
+const ID: &[u8] = b"Art-Net\0";
 pub const PORT: u16 = 0x1936;
+const PROTOCOL_VERSION: u16 = 14;
+
+const OP_POLL: u16 = 0x2000;
+const OP_COMMAND: u16 = 0x2400;
+const OP_DMX: u16 = 0x5000;
+const OP_SYNC: u16 = 0x5200;
 
-const DEFAULT_4_BYTES: &'static [u8; 4] = &[0; 4];
-const DEFAULT_6_BYTES: &'static [u8; 6] = &[0; 6];
+/// Length of a serialized ArtPoll, including the header and target Port-Addresses
+const POLL_LEN: usize = 18;
+/// Length of a serialized ArtPoll including the manufacturer and Oem targeting fields
+const POLL_WITH_FILTERS_LEN: usize = 22;
+/// Length of an ArtCommand packet excluding the data
+const COMMAND_HEADER_LEN: usize = 16;
+/// Length of an ArtSync packet
+const SYNC_LEN: usize = 14;
+/// Length of an ArtDmx packet excluding the DMX data
+const DMX_HEADER_LEN: usize = 18;
 
+const DEFAULT_4_BYTES: &[u8; 4] = &[0; 4];
+const DEFAULT_6_BYTES: &[u8; 6] = &[0; 6];
+
+/// An Art-Net packet.
+///
+/// Note: New op-codes are added to this enum as they are implemented so matches on it need a wildcard arm:
+///
+/// ```
+/// use tiny_artnet::{Art, Error};
+///
+/// fn describe(result: Result<Art, Error>) -> &'static str {
+///     match result {
+///         Ok(Art::Poll(_)) => "poll",
+///         Ok(Art::Dmx(_)) => "dmx",
+///         Ok(_) => "other packet",
+///         Err(Error::BadId) => "not art-net",
+///         Err(_) => "other error",
+///     }
+/// }
+///
+/// assert_eq!(describe(tiny_artnet::from_slice(b"Not Art-Net")), "not art-net");
+/// ```
+///
+/// Without it the match does not compile:
+///
+/// ```compile_fail
+/// fn describe(art: tiny_artnet::Art) -> &'static str {
+///     use tiny_artnet::Art::*;
+///
+///     match art {
+///         Poll(_) | PollReply(_) | Command(_) | Dmx(_) | Sync | TodControl(_) | Address(_) | Input(_) | RdmSub(_)
+///         | FirmwareReply(_) | DiagData(_) | TodRequest(_) | TodData(_) | Directory(_) | DirectoryReply(_) | Nzs(_)
+///         | Rdm(_) => "packet",
+///     }
+/// }
+/// ```
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Art<'a> {
     Poll(Poll),
-    // PollReply(PollReply),
+    PollReply(PollReply<'a>),
     Command(Command<'a>),
     Dmx(Dmx<'a>),
     Sync,
+    TodControl(TodControl),
+    Address(Address<'a>),
+    Input(Input<'a>),
+    RdmSub(RdmSub<'a>),
+    FirmwareReply(FirmwareReply),
+    DiagData(DiagData<'a>),
+    TodRequest(TodRequest<'a>),
+    TodData(TodData<'a>),
+    Directory(Directory),
+    DirectoryReply(DirectoryReply<'a>),
+    Nzs(Nzs<'a>),
+    Rdm(Rdm<'a>),
+}
+
+impl<'a> Art<'a> {
+    pub fn op_code(&self) -> OpCode {
+        match self {
+            Art::Poll(_) => OpCode::Poll,
+            Art::PollReply(_) => OpCode::PollReply,
+            Art::Command(_) => OpCode::Command,
+            Art::Dmx(_) => OpCode::Dmx,
+            Art::Sync => OpCode::Sync,
+            Art::TodControl(_) => OpCode::TodControl,
+            Art::Address(_) => OpCode::Address,
+            Art::Input(_) => OpCode::Input,
+            Art::RdmSub(_) => OpCode::RdmSub,
+            Art::FirmwareReply(_) => OpCode::FirmwareReply,
+            Art::DiagData(_) => OpCode::DiagData,
+            Art::TodRequest(_) => OpCode::TodRequest,
+            Art::TodData(_) => OpCode::TodData,
+            Art::Directory(_) => OpCode::Directory,
+            Art::DirectoryReply(_) => OpCode::DirectoryReply,
+            Art::Nzs(_) => OpCode::Nzs,
+            Art::Rdm(_) => OpCode::Rdm,
+        }
+    }
+
+    /// The Port-Address the packet is addressed to, for packets that have one.
+    pub fn port_address(&self) -> Option<&PortAddress> {
+        match self {
+            Art::Dmx(dmx) => Some(&dmx.port_address),
+            Art::Nzs(nzs) => Some(&nzs.port_address),
+            Art::Rdm(rdm) => Some(&rdm.port_address),
+            Art::TodControl(tod_control) => Some(&tod_control.port_address),
+            Art::TodData(tod_data) => Some(&tod_data.port_address),
+            _ => None,
+        }
+    }
+
+    /// The exact length of the packet once serialized, eg. for sizing the buffer a bridge forwards it with. Returns
+    /// None for packets this crate can parse but not serialize (ArtAddress and ArtRdmSub).
+    ///
+    /// Note: ArtCommand packets are assumed to be serialized without appending a trailing Nul, ArtDmx packets
+    /// without padding to 512 channels and ArtPollReply packets with `serialize` rather than `serialize_legacy`.
+    pub fn len_hint(&self) -> Option<usize> {
+        let len = match self {
+            Art::Poll(poll) => poll.serialized_len(),
+            Art::PollReply(poll_reply) => poll_reply.serialized_len(),
+            Art::Command(command) => command.serialized_len(false),
+            Art::Dmx(dmx) => dmx.serialized_len(false),
+            Art::Sync => SYNC_LEN,
+            Art::TodControl(tod_control) => tod_control.serialized_len(),
+            Art::Address(_) | Art::RdmSub(_) => return None,
+            Art::Input(input) => input.serialized_len(),
+            Art::FirmwareReply(firmware_reply) => firmware_reply.serialized_len(),
+            Art::DiagData(diag_data) => diag_data.serialized_len(),
+            Art::TodRequest(tod_request) => tod_request.serialized_len(),
+            Art::TodData(tod_data) => tod_data.serialized_len(),
+            Art::Directory(directory) => directory.serialized_len(),
+            Art::DirectoryReply(directory_reply) => directory_reply.serialized_len(),
+            Art::Nzs(nzs) => nzs.serialized_len(),
+            Art::Rdm(rdm) => rdm.serialized_len(),
+        };
+
+        Some(len)
+    }
 }
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error<'a> {
+    /// The datagram contains no data, eg. a UDP read returned 0 bytes.
+    Empty,
+    /// The datagram does not begin with the "Art-Net" ID and so is not an Art-Net packet.
+    BadId,
+    /// The datagram ended before the ID and op-code.
+    TooShort,
     UnsupportedProtocolVersion(u16),
     UnsupportedOpCode(u16),
     ParserError(nom::Err<nom::error::Error<&'a [u8]>>),
 }
 
+impl<'a> Error<'a> {
+    /// The byte offset into `original` (the datagram passed to `from_slice`) at which parsing failed, eg. for
+    /// logging "parse failed at byte 14". Returns None for errors that do not originate from a field parser.
+    pub fn offset(&self, original: &[u8]) -> Option<usize> {
+        let input = match self {
+            Error::ParserError(nom::Err::Error(err) | nom::Err::Failure(err)) => err.input,
+            _ => return None,
+        };
+
+        // The failing input is always a sub-slice of the original datagram
+        let offset = (input.as_ptr() as usize).checked_sub(original.as_ptr() as usize)?;
+        (offset <= original.len()).then_some(offset)
+    }
+}
+
 impl<'a> From<nom::Err<nom::error::Error<&'a [u8]>>> for Error<'a> {
     fn from(err: nom::Err<nom::error::Error<&'a [u8]>>) -> Self {
         Error::ParserError(err)
     }
 }
 
+#[derive(Debug)]
+pub enum SerializeError {
+    /// The buffer is smaller than the `needed` number of bytes required to hold the serialized packet.
+    BufferTooSmall { needed: usize },
+    /// The PollReply's `extra` data is longer than the `max` bytes available in the filler.
+    ExtraTooLong { max: usize },
+    /// The named field is larger than the `max` value its bits can encode.
+    OutOfRange { field: &'static str, max: u8 },
+}
+
+/// Parses only the packets a DMX lighting node needs (ArtPoll, ArtDmx and ArtSync), returning `Ok(None)` for any
+/// other op-code after reading just the header.
+pub fn from_slice_dmx_only<'a>(s: &'a [u8]) -> Result<Option<Art<'a>>, Error<'a>> {
+    match peek_op_code(s) {
+        Some(OP_POLL | OP_DMX | OP_SYNC) => from_slice(s).map(Some),
+        Some(_) => Ok(None),
+        // Not an Art-Net packet, let from_slice return the appropriate error
+        None => from_slice(s).map(Some),
+    }
+}
+
+/// An Art-Net packet annotated with the interface it was received on.
+///
+/// Nodes listening on multiple network interfaces need this to merge DMX from multiple sources and to route
+/// their replies.
+#[derive(Debug)]
+pub struct WithSource<'a> {
+    pub art: Art<'a>,
+    /// The IP address of the interface the packet was received on
+    pub source_ip: [u8; 4],
+    /// The node's physical port / interface index the packet was received on
+    pub physical: u8,
+}
+
+/// Parses a datagram (see `from_slice`) and annotates it with the interface it was received on.
+pub fn from_slice_with_source<'a>(
+    s: &'a [u8],
+    source_ip: [u8; 4],
+    physical: u8,
+) -> Result<WithSource<'a>, Error<'a>> {
+    Ok(WithSource {
+        art: from_slice(s)?,
+        source_ip,
+        physical,
+    })
+}
+
+/// Returns true if the datagram begins with the "Art-Net" ID.
+pub fn is_art_net(s: &[u8]) -> bool {
+    s.starts_with(ID)
+}
+
+/// Scans a byte stream (eg. a pcap payload) for the next "Art-Net" ID that begins a parseable packet, returning its
+/// offset into the stream along with the parsed packet.
+///
+/// Corrupted packets are skipped by resynchronizing on the following ID.
+pub fn find_next_packet<'a>(s: &'a [u8]) -> Option<(usize, Art<'a>)> {
+    (0..s.len())
+        .filter(|&offset| is_art_net(&s[offset..]))
+        .find_map(|offset| {
+            let packet = &s[offset..];
+            let packet = &packet[..bounded_packet_len(packet)];
+
+            Some((offset, from_slice(packet).ok()?))
+        })
+}
+
+/// The length of the packet at the start of a byte stream `s`, so that it can be parsed without consuming the
+/// packets that follow it.
+///
+/// Packets are bounded by their `packet_len`, except for ArtPoll and ArtPollReply (which may be shorter than their
+/// assumed length) and unsupported op-codes which end where the next ID begins.
+fn bounded_packet_len(s: &[u8]) -> usize {
+    let next_id = (ID.len()..s.len())
+        .find(|&offset| is_art_net(&s[offset..]))
+        .unwrap_or(s.len());
+
+    match (peek_op_code(s), packet_len(s)) {
+        (Some(OP_POLL | poll_reply::OP_POLL_REPLY), Some(len)) => len.min(next_id),
+        (_, Some(len)) => len.min(s.len()),
+        (_, None) => next_id,
+    }
+}
+
+/// Parses each recorded datagram in order (see `from_slice`) and passes the result to the consumer, eg. for testing
+/// a node's logic against captured traffic without opening a socket.
+pub fn replay<'a>(datagrams: &[&'a [u8]], consumer: &mut impl FnMut(Result<Art<'a>, Error<'a>>)) {
+    for datagram in datagrams {
+        consumer(from_slice(datagram));
+    }
+}
+
+/// Reads the op-code of an Art-Net packet without parsing the rest of it. Returns None if the datagram is not an
+/// Art-Net packet or is too short to contain an op-code.
+pub fn peek_op_code(s: &[u8]) -> Option<u16> {
+    let (_s, op_code) = read_artnet_opcode(s.strip_prefix(ID)?).ok()?;
+
+    Some(op_code)
+}
+
+/// The total length of the packet beginning at the start of `s`, read from its header and length fields, eg. for
+/// framing packets received over a byte stream rather than as datagrams.
+///
+/// Returns None if `s` is too short to contain the fields the length depends on, or if the op-code is not one this
+/// crate parses.
+///
+/// Note: ArtPoll and ArtPollReply are assumed to be their full Art-Net 4 length since older, shorter packets cannot
+/// be told apart from their headers. Their length is a maximum, a shorter packet ends where the next packet's ID
+/// begins (see `ByteStreamParser`).
+pub fn packet_len(s: &[u8]) -> Option<usize> {
+    packet_len_for_op_code(s)?
+}
+
+// The outer Option is None for unsupported op-codes, the inner Option is None when more bytes are needed
+fn packet_len_for_op_code(s: &[u8]) -> Option<Option<usize>> {
+    let op_code = peek_op_code(s)?;
+
+    let u8_at = |offset: usize| s.get(offset).map(|n| *n as usize);
+    let be_u16_at =
+        |offset: usize| Some(u16::from_be_bytes([*s.get(offset)?, *s.get(offset + 1)?]) as usize);
+    let le_u16_at =
+        |offset: usize| Some(u16::from_le_bytes([*s.get(offset)?, *s.get(offset + 1)?]) as usize);
+
+    let len = match op_code {
+        OP_POLL => Some(POLL_WITH_FILTERS_LEN),
+        poll_reply::OP_POLL_REPLY => Some(poll_reply::POLL_REPLY_LEN),
+        OP_COMMAND => le_u16_at(14).map(|len| COMMAND_HEADER_LEN + len),
+        OP_DMX => be_u16_at(16).map(|len| DMX_HEADER_LEN + len),
+        nzs::OP_NZS => be_u16_at(16).map(|len| nzs::NZS_HEADER_LEN + len),
+        OP_SYNC => Some(SYNC_LEN),
+        address::OP_ADDRESS => Some(address::ADDRESS_LEN),
+        directory::OP_DIRECTORY => Some(directory::DIRECTORY_LEN),
+        directory::OP_DIRECTORY_REPLY => Some(directory::DIRECTORY_REPLY_LEN),
+        diag_data::OP_DIAG_DATA => be_u16_at(16).map(|len| diag_data::DIAG_DATA_HEADER_LEN + len),
+        firmware_reply::OP_FIRMWARE_REPLY => Some(firmware_reply::FIRMWARE_REPLY_LEN),
+        input::OP_INPUT => Some(input::INPUT_LEN),
+        rdm::OP_RDM => s
+            .get(rdm::RDM_HEADER_LEN..)
+            .and_then(rdm::rdm_message_len)
+            .map(|message_len| rdm::RDM_HEADER_LEN + message_len),
+        rdm_sub::OP_RDM_SUB => u8_at(21)
+            .zip(be_u16_at(26))
+            .map(|(command_class, sub_count)| {
+                rdm_sub::RDM_SUB_HEADER_LEN
+                    + rdm_sub::data_len(command_class as u8, sub_count as u16)
+            }),
+        tod_control::OP_TOD_CONTROL => Some(tod_control::TOD_CONTROL_LEN),
+        tod_data::OP_TOD_DATA => {
+            u8_at(27).map(|uid_count| tod_data::TOD_DATA_HEADER_LEN + uid_count * 6)
+        }
+        tod_request::OP_TOD_REQUEST => {
+            u8_at(23).map(|address_count| tod_request::TOD_REQUEST_HEADER_LEN + address_count)
+        }
+        _ => return None,
+    };
+
+    Some(len)
+}
+
+/// Options controlling how packets are parsed by `from_slice_with_options`.
+#[derive(Debug, Default, Clone)]
+pub struct ParseOptions {
+    /// Reject packets that break the spec in ways that are otherwise tolerated, eg. a Port-Address with the reserved
+    /// top bit set or an ArtDmx containing less data than its length field declares. Defaults to false.
+    pub strict: bool,
+    /// Return ArtDmx packets with empty `data` without validating their length, eg. for statistics collectors that
+    /// only need the sequence and Port-Address. Defaults to false.
+    pub skip_dmx_data: bool,
+}
+
 pub fn from_slice<'a>(s: &'a [u8]) -> Result<Art<'a>, Error<'a>> {
+    from_slice_with_options(s, &ParseOptions::default())
+}
+
+/// The fields common to every Art-Net packet. See `parse_header`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    /// The little endian op-code. See `OpCode::from_u16`.
+    pub op_code: u16,
+    /// The protocol version, None for ArtPollReply which is the only packet without one.
+    pub protocol_version: Option<u16>,
+}
+
+/// Parses only the ID, op-code and protocol version of a packet, returning the header and the unparsed body.
+///
+/// Routers that forward packets based on their op-code can use this to avoid parsing (and validating) bodies they
+/// do not need. `from_slice` can parse the full packet later if needed.
+pub fn parse_header<'a>(s: &'a [u8]) -> Result<(Header, &'a [u8]), Error<'a>> {
+    if s.is_empty() {
+        return Err(Error::Empty);
+    }
+
     // ID
-    let (s, _) = tag(ID)(s)?;
+    if !is_art_net(s) {
+        // A datagram that is a truncated copy of the ID may still have been an Art-Net packet
+        return Err(if ID.starts_with(s) {
+            Error::TooShort
+        } else {
+            Error::BadId
+        });
+    }
+
+    let op_code = peek_op_code(s).ok_or(Error::TooShort)?;
+    let s = &s[ID.len() + 2..];
+
+    // ArtPollReply is the only packet without a protocol version
+    if op_code == poll_reply::OP_POLL_REPLY {
+        let header = Header {
+            op_code,
+            protocol_version: None,
+        };
+
+        return Ok((header, s));
+    }
+
+    let (s, protocol_version) = read_protocol_version(s)?;
+
+    let header = Header {
+        op_code,
+        protocol_version: Some(protocol_version),
+    };
+
+    Ok((header, s))
+}
+
+/// Parses a datagram using the given options.
+///
+/// With the `tracing` feature enabled a debug event is emitted for each parsed packet and a warn event (including
+/// the offset the parser failed at) for each error.
+pub fn from_slice_with_options<'a>(
+    s: &'a [u8],
+    options: &ParseOptions,
+) -> Result<Art<'a>, Error<'a>> {
+    let result = parse_art(s, options);
+
+    #[cfg(feature = "tracing")]
+    match &result {
+        Ok(art) => {
+            tracing::debug!(op_code = ?art.op_code(), len = s.len(), "Parsed Art-Net packet")
+        }
+        Err(err) => {
+            tracing::warn!(error = ?err, offset = ?err.offset(s), "Unable to parse Art-Net packet")
+        }
+    }
+
+    result
+}
 
-    let (s, op_code) = le_u16(s)?;
-    let (s, protocol_version): (&'a [u8], u16) = be_u16(s)?;
+fn parse_art<'a>(s: &'a [u8], options: &ParseOptions) -> Result<Art<'a>, Error<'a>> {
+    let (header, s) = parse_header(s)?;
+    let op_code = header.op_code;
 
-    if protocol_version > 14 {
-        return Err(Error::UnsupportedProtocolVersion(protocol_version));
+    match header.protocol_version {
+        None => return Ok(Art::PollReply(poll_reply::parse_poll_reply(s)?)),
+        Some(protocol_version) if protocol_version > 14 => {
+            return Err(Error::UnsupportedProtocolVersion(protocol_version));
+        }
+        Some(_) => {}
     }
 
     let message = match op_code {
-        0x2000 => Art::Poll(parse_poll(s)?),
-        // poll_reply::OP_POLL_REPLY => Art::PollReply(poll_reply::from_str(s)?),
-        0x2400 => Art::Command(parse_command(s)?),
-        0x5000 => Art::Dmx(parse_dmx(s)?),
-        0x5200 => parse_sync(s).map(|_| Art::Sync)?,
+        OP_POLL => Art::Poll(parse_poll(s)?),
+        OP_COMMAND => Art::Command(parse_command(s)?),
+        OP_DMX => Art::Dmx(parse_dmx(s, options)?),
+        nzs::OP_NZS => Art::Nzs(nzs::parse_nzs(s, options)?),
+        OP_SYNC => parse_sync(s).map(|_| Art::Sync)?,
+        address::OP_ADDRESS => Art::Address(address::parse_address(s)?),
+        directory::OP_DIRECTORY => Art::Directory(directory::parse_directory(s)?),
+        directory::OP_DIRECTORY_REPLY => Art::DirectoryReply(directory::parse_directory_reply(s)?),
+        diag_data::OP_DIAG_DATA => Art::DiagData(diag_data::parse_diag_data(s)?),
+        firmware_reply::OP_FIRMWARE_REPLY => {
+            Art::FirmwareReply(firmware_reply::parse_firmware_reply(s)?)
+        }
+        input::OP_INPUT => Art::Input(input::parse_input(s)?),
+        rdm::OP_RDM => Art::Rdm(rdm::parse_rdm(s)?),
+        rdm_sub::OP_RDM_SUB => Art::RdmSub(rdm_sub::parse_rdm_sub(s)?),
+        tod_control::OP_TOD_CONTROL => Art::TodControl(tod_control::parse_tod_control(s)?),
+        tod_data::OP_TOD_DATA => Art::TodData(tod_data::parse_tod_data(s)?),
+        tod_request::OP_TOD_REQUEST => Art::TodRequest(tod_request::parse_tod_request(s)?),
         _ => return Err(Error::UnsupportedOpCode(op_code)),
     };
 
     Ok(message)
 }
 
+// Appends the ID, op-code and protocol version that begin every packet (except ArtPollReply)
+fn put_header<S: ByteSink>(buf: &mut S, op_code: u16) {
+    buf.put_slice(ID);
+    buf.put_u16_le(op_code);
+    buf.put_u16(PROTOCOL_VERSION);
+}
+
 /// (ESTAManLo, ESTAManHi)
 pub type ESTAManufacturerCode = (char, char);
 
-fn parse_esta_manufacturer_code<'a>(s: &'a [u8]) -> IResult<&'a [u8], ESTAManufacturerCode> {
+/// The ESTA manufacturer code 0xFFFF used by ArtCommand to address every manufacturer's equipment.
+pub const ESTA_BROADCAST: ESTAManufacturerCode = ('\u{ff}', '\u{ff}');
+
+fn parse_esta_manufacturer_code(s: &[u8]) -> IResult<&[u8], ESTAManufacturerCode> {
     let (s, (lo, hi)) = tuple((number::u8, number::u8))(s)?;
     Ok((s, (lo as char, hi as char)))
 }
 
-pub fn put_esta_manufacturer_code<B: BufMut>(
-    buf: &mut B,
+pub fn put_esta_manufacturer_code<S: ByteSink>(
+    buf: &mut S,
     manufacturer_code: &ESTAManufacturerCode,
 ) {
     buf.put_u8(manufacturer_code.0 as u8);
@@ -88,24 +612,55 @@ pub fn put_esta_manufacturer_code<B: BufMut>(
 /// Bits:
 ///     | 15 | 8-14 | 4-7    | 0-3      |
 ///     | 0  | Net  | SubNet | Universe |
-#[derive(Debug)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct PortAddress {
     pub net: u8,
     pub sub_net: u8,
     pub universe: u8,
 }
 
-fn parse_port_address<'a>(s: &'a [u8]) -> IResult<&'a [u8], PortAddress> {
+/// Returned by `PortAddress::to_artnet3` when the Port-Address has a Net that an 8-bit Port-Address cannot represent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetTruncated {
+    /// The Net that would have been lost
+    pub net: u8,
+}
+
+/// Composes the Port-Address of one of a node's ports from the `net_switch`, `sub_switch` and the port's `swin` or
+/// `swout` value advertised in its ArtPollReply.
+pub fn port_address_from_reply(net_switch: u8, sub_switch: u8, sw: u8) -> PortAddress {
+    PortAddress {
+        net: net_switch & 0x7F,
+        sub_net: sub_switch & 0x0F,
+        universe: sw & 0x0F,
+    }
+}
+
+/// Parses a Port-Address sent as a SubUni byte followed by a Net byte (ie. a little endian 15-bit word).
+///
+/// This is the encoding used by ArtDmx. See `be_u15_port_address` for the big endian encoding.
+///
+/// The reserved top bit is ignored unless `strict` is set, in which case Port-Addresses with it set are rejected.
+fn parse_port_address(s: &[u8], strict: bool) -> IResult<&[u8], PortAddress> {
     use nom::bits::complete as bits;
 
-    let (s, (sub_net, universe, _, net)): (&[u8], (u8, u8, u8, u8)) = nom::bits::bits(tuple((
-        // Low Byte (SubUni)
-        bits::take::<&[u8], u8, usize, nom::error::Error<(&[u8], usize)>>(4usize),
-        bits::take(4usize),
-        // High Byte (Net)
-        bits::take(1usize),
-        bits::take(7usize),
-    )))(s)?;
+    let input = s;
+    let (s, (sub_net, universe, reserved, net)): (&[u8], (u8, u8, u8, u8)) =
+        nom::bits::bits(tuple((
+            // Low Byte (SubUni)
+            bits::take::<&[u8], u8, usize, nom::error::Error<(&[u8], usize)>>(4usize),
+            bits::take(4usize),
+            // High Byte (Net)
+            bits::take(1usize),
+            bits::take(7usize),
+        )))(s)?;
+
+    if strict && reserved != 0 {
+        return Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Verify,
+        )));
+    }
 
     let port_address = PortAddress {
         net,
@@ -116,69 +671,320 @@ fn parse_port_address<'a>(s: &'a [u8]) -> IResult<&'a [u8], PortAddress> {
     Ok((s, port_address))
 }
 
+/// Appends a Port-Address as a SubUni byte followed by a Net byte. See `parse_port_address`.
+fn put_port_address<S: ByteSink>(buf: &mut S, port_address: &PortAddress) {
+    let (net, subuni) = port_address.to_net_subuni();
+
+    buf.put_u8(subuni);
+    buf.put_u8(net);
+}
+
+/// Parses a Port-Address sent as a big endian 15-bit word (ie. a Hi byte containing the Net followed by a Lo byte
+/// containing the SubNet and Universe), ignoring the reserved top bit.
+///
+/// This is the encoding used by the ArtPoll TargetPortAddressTop/Bottom fields whereas ArtDmx sends the Port-Address
+/// little endian (SubUni then Net). Mixing up the two encodings is a common source of Art-Net bugs.
+pub fn be_u15_port_address(s: &[u8]) -> IResult<&[u8], PortAddress> {
+    let (s, word) = be_u16(s)?;
+
+    // Masking to 15 bits guarentees the index is in range
+    let port_address = PortAddress::from_index((word & 0x7FFF) as usize).unwrap();
+
+    Ok((s, port_address))
+}
+
 impl PortAddress {
+    /// The largest logical Port-Address index (a 15-bit number)
+    pub const MAX_INDEX: usize = 0x7FFF;
+
     /// Combines the Net, SubNet and Universe into a single usize index. Note this is not the same as the little endian u16 sent over the wire.
     pub fn as_index(&self) -> usize {
-        (self.net as usize >> 14) + (self.sub_net as usize >> 7) + (self.universe as usize)
+        ((self.net as usize) << 8) | ((self.sub_net as usize) << 4) | (self.universe as usize)
+    }
+
+    /// Like `as_index` but returns None if any component is out of range (Net above 127, SubNet or Universe above
+    /// 15), eg. when the struct was constructed directly, rather than producing an index for a different address.
+    pub fn checked_index(&self) -> Option<usize> {
+        if self.net > 0x7F || self.sub_net > 0x0F || self.universe > 0x0F {
+            return None;
+        }
+
+        Some(self.as_index())
+    }
+
+    /// Splits a logical index (see `as_index`) back into its Net, SubNet and Universe. Returns None if the index does not fit in 15 bits.
+    pub fn from_index(index: usize) -> Option<PortAddress> {
+        if index > Self::MAX_INDEX {
+            return None;
+        }
+
+        Some(PortAddress {
+            net: (index >> 8) as u8,
+            sub_net: ((index >> 4) & 0x0F) as u8,
+            universe: (index & 0x0F) as u8,
+        })
+    }
+
+    /// Splits the Port-Address into the separate Net (0-127) and SubUni (SubNet << 4 | Universe) bytes used by
+    /// several op-codes.
+    pub fn to_net_subuni(&self) -> (u8, u8) {
+        (
+            self.net & 0x7F,
+            (self.sub_net << 4) | (self.universe & 0x0F),
+        )
+    }
+
+    /// The 8-bit SubUni byte (SubNet << 4 | Universe) used by nodes without a Net, eg. when bridging to older
+    /// devices that only support 8-bit Port-Addresses. Returns an error rather than silently dropping a non-zero
+    /// Net, which would route the data to a different universe.
+    pub fn to_artnet3(&self) -> Result<u8, NetTruncated> {
+        if self.net != 0 {
+            return Err(NetTruncated { net: self.net });
+        }
+
+        Ok(self.to_net_subuni().1)
+    }
+
+    /// Combines separate Net and SubUni bytes into a Port-Address, ignoring the reserved top bit of the Net.
+    pub fn from_net_subuni(net: u8, subuni: u8) -> PortAddress {
+        PortAddress {
+            net: net & 0x7F,
+            sub_net: subuni >> 4,
+            universe: subuni & 0x0F,
+        }
+    }
+
+    /// Parses a Port-Address sent as a SubUni byte followed by a Net byte (the ArtDmx encoding), ignoring the
+    /// reserved top bit. Useful for composing parsers for op-codes this crate does not implement.
+    pub fn parse(s: &[u8]) -> IResult<&[u8], PortAddress> {
+        parse_port_address(s, false)
+    }
+
+    /// Serializes the Port-Address as a SubUni byte followed by a Net byte (see `parse`), returning the number of
+    /// bytes written.
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+        put_port_address(&mut buf, self);
+
+        buf.written()
+    }
+
+    /// The Port-Address with the next logical index (see `as_index`), eg. universe 15 is followed by universe 0 of
+    /// the next SubNet. Returns None at the end of the 15-bit address space.
+    pub fn next(&self) -> Option<PortAddress> {
+        PortAddress::from_index(self.as_index() + 1)
+    }
+
+    /// Adds `n` to the logical index (see `as_index`), returning None if the result does not fit in 15 bits.
+    pub fn checked_add(&self, n: usize) -> Option<PortAddress> {
+        PortAddress::from_index(self.as_index().checked_add(n)?)
+    }
+
+    /// Iterates over every Port-Address from start to end (inclusive) in logical index order, eg. crossing from
+    /// universe 15 of one SubNet into universe 0 of the next.
+    ///
+    /// The iterator stops at the end of the 15-bit address space rather than wrapping.
+    pub fn range(start: PortAddress, end: PortAddress) -> impl Iterator<Item = PortAddress> {
+        let end = end.as_index().min(Self::MAX_INDEX);
+
+        (start.as_index()..=end).filter_map(PortAddress::from_index)
+    }
+}
+
+/// The logical 15-bit Port-Address (see `PortAddress::as_index`)
+impl From<PortAddress> for u16 {
+    fn from(port_address: PortAddress) -> Self {
+        port_address.as_index() as u16
     }
 }
 
+/// The logical Port-Address index (see `PortAddress::as_index`)
+impl From<PortAddress> for usize {
+    fn from(port_address: PortAddress) -> Self {
+        port_address.as_index()
+    }
+}
+
+fn parse_array<const N: usize>(s: &[u8]) -> IResult<&[u8], &[u8; N]> {
+    let (s, bytes) = take(N)(s)?;
+    // take guarentees that exactly N bytes were returned
+    Ok((s, bytes.try_into().unwrap()))
+}
+
+// Parses a Nul terminated (or padded) ASCII string from a N byte field
+fn parse_padded_str<const N: usize>(s: &[u8]) -> IResult<&[u8], &str> {
+    let (s, bytes) = take(N)(s)?;
+    let len = bytes.iter().position(|b| *b == 0).unwrap_or(N);
+
+    let string = core::str::from_utf8(&bytes[..len])
+        .map_err(|_| nom::Err::Error(nom::error::Error::new(bytes, nom::error::ErrorKind::Char)))?;
+
+    Ok((s, string))
+}
+
 // Appends a Nul terminated ASCII string truncated (or padded) to N bytes
-fn put_padded_str<const N: usize, B: BufMut>(mut buf: B, input: &str) {
-    let mut padded_bytes = [0; N];
+fn put_padded_str<const N: usize, S: ByteSink>(mut buf: S, input: &str) {
+    const NULS: [u8; 64] = [0; 64];
 
     let bytes = input.as_bytes();
-    // Truncate to N minus 1 to leave 1 byte for the NUL character
-    let truncated_bytes = if bytes.len() > N - 1 {
-        &bytes[..N - 1]
-    } else {
-        &bytes[..]
-    };
-
-    // Put the truncated bytes into the padded buffer - this guarentees that the length is always N
-    (&mut padded_bytes[..]).put_slice(truncated_bytes);
+    // Truncate to N minus 1 to leave at least 1 byte for the NUL character
+    let truncated_bytes = &bytes[..bytes.len().min(N - 1)];
+    buf.put_slice(truncated_bytes);
 
-    buf.put_slice(&padded_bytes);
+    // Write the NUL padding straight into the sink rather than copying through an intermediate [0; N]
+    let mut padding = N - truncated_bytes.len();
+    while padding > 0 {
+        let chunk = padding.min(NULS.len());
+        buf.put_slice(&NULS[..chunk]);
+        padding -= chunk;
+    }
 }
 
 #[derive(Debug)]
 pub struct Poll {
     pub flags: u8,
     pub min_diagnostic_priority: u8,
+    /// The range of Port-Addresses (bottom..=top) the controller wants replies from when targeted mode is enabled.
     pub target_port_addresses: RangeInclusive<u16>,
+    /// True if the controller sent a top Port-Address below the bottom one. The range is normalized so that it is
+    /// never empty and this records that the bounds were swapped (they are swapped back when serializing).
+    pub targeting_inverted: bool,
+    /// Only nodes from this manufacturer should reply. None (sent as 0xFFFF) targets every manufacturer.
+    pub esta_filter: Option<ESTAManufacturerCode>,
+    /// Only nodes with this Oem code should reply. None (sent as 0xFFFF) targets every Oem.
+    pub oem_filter: Option<u16>,
 }
 
 fn parse_poll<'a>(s: &'a [u8]) -> Result<Poll, Error<'a>> {
     let (s, flags) = number::u8(s)?;
     let (s, min_diagnostic_priority) = number::u8(s)?;
 
-    let target_port_addresses = if !s.is_empty() {
-        let (s, target_port_top): (&'a [u8], u16) = be_u16(s)?;
-        let (_s, target_port_bottom): (&'a [u8], u16) = be_u16(s)?;
+    // The remaining fields were added in later versions of the spec. Each is only read if it is complete and any
+    // bytes beyond the fields modelled here (eg. added by newer controllers) are ignored.
+    let (s, targets) = opt(tuple((be_u16, be_u16)))(s)?;
+    let (target_port_addresses, targeting_inverted) = match targets {
+        Some((target_port_top, target_port_bottom)) if target_port_top < target_port_bottom => {
+            (target_port_top..=target_port_bottom, true)
+        }
+        Some((target_port_top, target_port_bottom)) => {
+            (target_port_bottom..=target_port_top, false)
+        }
+        None => (0..=u16::MAX, false),
+    };
 
-        target_port_top..=target_port_bottom
-    } else {
-        0..=u16::MAX
+    // Manufacturer and Oem targeting. Note: unlike elsewhere the ESTA code is sent Hi byte first.
+    let (_s, filters) = opt(tuple((number::u8, number::u8, be_u16)))(s)?;
+    let (esta_filter, oem_filter) = match filters {
+        Some((esta_hi, esta_lo, oem)) => {
+            let esta_filter = (esta_lo as char, esta_hi as char);
+            (
+                Some(esta_filter).filter(|code| *code != ESTA_BROADCAST),
+                Some(oem).filter(|oem| *oem != 0xFFFF),
+            )
+        }
+        None => (None, None),
     };
 
     Ok(Poll {
         flags,
         min_diagnostic_priority,
         target_port_addresses,
+        targeting_inverted,
+        esta_filter,
+        oem_filter,
     })
 }
 
-#[derive(Debug)]
+impl Default for Poll {
+    fn default() -> Self {
+        Self {
+            flags: 0,
+            min_diagnostic_priority: 0,
+            target_port_addresses: 0..=u16::MAX,
+            targeting_inverted: false,
+            esta_filter: None,
+            oem_filter: None,
+        }
+    }
+}
+
+impl Poll {
+    /// Decodes the `min_diagnostic_priority` field. Returns None for priorities not defined by the spec.
+    pub fn diagnostic_priority(&self) -> Option<DiagnosticPriority> {
+        DiagnosticPriority::from_u8(self.min_diagnostic_priority)
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        if self.has_filters() {
+            POLL_WITH_FILTERS_LEN
+        } else {
+            POLL_LEN
+        }
+    }
+
+    fn has_filters(&self) -> bool {
+        self.esta_filter.is_some() || self.oem_filter.is_some()
+    }
+
+    /// Serializes the ArtPoll into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the ArtPoll into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    ///
+    /// The manufacturer and Oem targeting fields are only sent if either filter is set.
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+
+        put_header(&mut buf, OP_POLL);
+        buf.put_u8(self.flags);
+        buf.put_u8(self.min_diagnostic_priority);
+        let (bottom, top) = (
+            *self.target_port_addresses.start(),
+            *self.target_port_addresses.end(),
+        );
+
+        if self.targeting_inverted {
+            buf.put_u16(bottom);
+            buf.put_u16(top);
+        } else {
+            buf.put_u16(top);
+            buf.put_u16(bottom);
+        }
+
+        if self.has_filters() {
+            let (esta_lo, esta_hi) = self.esta_filter.unwrap_or(ESTA_BROADCAST);
+            buf.put_u8(esta_hi as u8);
+            buf.put_u8(esta_lo as u8);
+            buf.put_u16(self.oem_filter.unwrap_or(0xFFFF));
+        }
+
+        buf.written()
+    }
+}
+
+/// Whether an ArtCommand's data is `Key=Value&` text or an opaque binary payload. See `Command::payload_kind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadKind {
+    Text,
+    Binary,
+}
+
+#[derive(Debug, Default)]
 pub struct Command<'a> {
     pub esta_manufacturer_code: ESTAManufacturerCode,
+    /// The command text, including the trailing Nul if the sender counted one in the length.
     pub data: &'a [u8],
 }
 
 fn parse_command<'a>(s: &'a [u8]) -> Result<Command<'a>, Error<'a>> {
     let (s, esta_manufacturer_code) = parse_esta_manufacturer_code(s)?;
-    let (s, length): (&'a [u8], u16) = le_u16(s)?;
-
-    let data = &s[..length as usize];
+    let (s, length) = read_command_length(s)?;
+    let (_s, data) = take(length)(s)?;
 
     Ok(Command {
         esta_manufacturer_code,
@@ -186,7 +992,85 @@ fn parse_command<'a>(s: &'a [u8]) -> Result<Command<'a>, Error<'a>> {
     })
 }
 
-#[derive(Debug)]
+impl<'a> Command<'a> {
+    pub fn new(esta_manufacturer_code: ESTAManufacturerCode, data: &'a [u8]) -> Self {
+        Self {
+            esta_manufacturer_code,
+            data,
+        }
+    }
+
+    /// Guesses whether the data is `Key=Value&` text (printable ASCII containing an '=', ignoring any trailing Nul) or
+    /// a binary payload, so binary payloads are not misparsed as text.
+    pub fn payload_kind(&self) -> PayloadKind {
+        let text = self.data.strip_suffix(&[0]).unwrap_or(self.data);
+
+        if text.contains(&b'=') && text.iter().all(|b| b.is_ascii_graphic() || *b == b' ') {
+            PayloadKind::Text
+        } else {
+            PayloadKind::Binary
+        }
+    }
+
+    /// True if the command should be acted on by equipment with the given ESTA manufacturer code, ie. it is addressed
+    /// to that manufacturer or to every manufacturer (`ESTA_BROADCAST`).
+    ///
+    /// Passing `ESTA_BROADCAST` matches every command.
+    pub fn is_for(&self, esta_manufacturer_code: ESTAManufacturerCode) -> bool {
+        esta_manufacturer_code == ESTA_BROADCAST
+            || self.esta_manufacturer_code == ESTA_BROADCAST
+            || self.esta_manufacturer_code == esta_manufacturer_code
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self, include_trailing_nul: bool) -> usize {
+        COMMAND_HEADER_LEN + self.data.len() + self.appends_nul(include_trailing_nul) as usize
+    }
+
+    /// Serializes the ArtCommand into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(
+        &self,
+        buf: &mut [u8],
+        include_trailing_nul: bool,
+    ) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len(include_trailing_nul));
+
+        Ok(self.serialize(buf, include_trailing_nul))
+    }
+
+    // Parsed commands keep any trailing Nul in their data so only add one if it is missing
+    fn appends_nul(&self, include_trailing_nul: bool) -> bool {
+        include_trailing_nul && self.data.last() != Some(&0)
+    }
+
+    /// Serializes the ArtCommand into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    ///
+    /// Some commands expect the text to be Nul terminated with the Nul counted in the length field while others
+    /// do not. If `include_trailing_nul` is set a Nul is appended to the data (unless it already ends with one) and
+    /// included in the length. This means a parsed command is reproduced byte for byte.
+    pub fn serialize<S: ByteSink>(&self, sink: S, include_trailing_nul: bool) -> usize {
+        let mut buf = Counter::new(sink);
+
+        let append_nul = self.appends_nul(include_trailing_nul);
+        let length = self.data.len() + append_nul as usize;
+
+        put_header(&mut buf, OP_COMMAND);
+        put_esta_manufacturer_code(&mut buf, &self.esta_manufacturer_code);
+        buf.put_u16_le(length as u16);
+        buf.put_slice(self.data);
+
+        if append_nul {
+            buf.put_u8(0);
+        }
+
+        buf.written()
+    }
+}
+
+/// ArtDmx carries zero start code DMX512 data for a single universe.
+///
+/// The spec also calls this packet ArtOutput (op-code OpOutput / OpDmx 0x5000), this crate uses ArtDmx throughout.
+#[derive(Debug, Default)]
 pub struct Dmx<'a> {
     /// The sequence number is used to ensure that
     /// ArtDmx packets are used in the correct order.
@@ -216,28 +1100,555 @@ pub struct Dmx<'a> {
     ///     | 0  | Net  | SubNet | Universe |
     pub port_address: PortAddress,
     pub data: &'a [u8],
+    /// Set if the packet contained fewer bytes of data than its length field declared. Unless parsing in strict
+    /// mode `data` is clamped to the bytes that were received rather than returning an error.
+    pub truncated: bool,
 }
 
-fn parse_dmx<'a>(s: &'a [u8]) -> Result<Dmx<'a>, Error<'a>> {
+fn parse_dmx<'a>(s: &'a [u8], options: &ParseOptions) -> Result<Dmx<'a>, Error<'a>> {
     let (s, sequence) = number::u8(s)?;
     let (s, physical) = number::u8(s)?;
-    let (s, port_address) = parse_port_address(s)?;
+    let (s, port_address) = parse_port_address(s, options.strict)?;
 
-    let (s, length): (&'a [u8], u16) = be_u16(s)?;
+    if options.skip_dmx_data {
+        return Ok(Dmx {
+            sequence,
+            physical,
+            port_address,
+            data: &[],
+            truncated: false,
+        });
+    }
+
+    let (s, length) = read_dmx_length(s)?;
+
+    // Some senders declare a longer length than the data they send
+    let truncated = s.len() < length as usize;
 
-    let data = &s[..length as usize];
+    let data = if truncated && !options.strict {
+        s
+    } else {
+        take(length)(s)?.1
+    };
 
     Ok(Dmx {
         sequence,
         physical,
         port_address,
         data,
+        truncated,
     })
 }
 
+impl<'a> Dmx<'a> {
+    /// Creates an ArtDmx for the data with sequencing disabled and a physical port of 0.
+    pub fn new(port_address: PortAddress, data: &'a [u8]) -> Self {
+        Self {
+            port_address,
+            data,
+            ..Default::default()
+        }
+    }
+
+    /// The flat 15-bit universe number of the frame's Port-Address (see `PortAddress::as_index`).
+    pub fn universe(&self) -> u16 {
+        self.port_address.as_index() as u16
+    }
+
+    /// The `len` channels of a fixture's footprint starting at the 1-based DMX channel `start`, or None if the
+    /// footprint does not fit in the frame's data.
+    pub fn channels_range(&self, start: u16, len: u16) -> Option<&'a [u8]> {
+        let start = (start as usize).checked_sub(1)?;
+
+        self.data.get(start..start + len as usize)
+    }
+
+    /// Renders the active (non-zero) channels as space separated 1-based `channel:value` pairs, eg. "1:255 4:10", for
+    /// debugging tools.
+    #[cfg(feature = "alloc")]
+    pub fn debug_table(&self) -> alloc::string::String {
+        use core::fmt::Write;
+
+        let mut table = alloc::string::String::new();
+
+        for (index, value) in self
+            .data
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| **value != 0)
+        {
+            if !table.is_empty() {
+                table.push(' ');
+            }
+            // Writing to a String never fails
+            let _ = write!(table, "{}:{}", index + 1, value);
+        }
+
+        table
+    }
+
+    /// True if every channel is zero (a blackout)
+    pub fn is_blackout(&self) -> bool {
+        self.data.iter().all(|value| *value == 0)
+    }
+
+    /// Compares the frame against the previously received data for the same Port-Address, yielding the (channel
+    /// index, new value) of each channel that changed.
+    ///
+    /// Nodes using the Art-Net 4 delta output style only need to output the changed channels. Channels missing from
+    /// the previous frame are treated as changed.
+    pub fn changed_channels<'p>(
+        &'p self,
+        previous: &'p [u8],
+    ) -> impl Iterator<Item = (usize, u8)> + 'p {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(move |(index, value)| previous.get(*index) != Some(value))
+            .map(|(index, value)| (index, *value))
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self, pad_to_512: bool) -> usize {
+        DMX_HEADER_LEN + self.padded_data_len(pad_to_512)
+    }
+
+    /// Serializes the ArtDmx into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8], pad_to_512: bool) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len(pad_to_512));
+
+        Ok(self.serialize(buf, pad_to_512))
+    }
+
+    /// Serializes the ArtDmx into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    ///
+    /// The spec requires an even number of DMX channels so odd length data is padded with a trailing zero. Some
+    /// controllers expect every frame to carry a full universe, if `pad_to_512` is true the data is zero-filled to
+    /// 512 channels.
+    pub fn serialize<S: ByteSink>(&self, sink: S, pad_to_512: bool) -> usize {
+        self.put(sink, self.padded_data_len(pad_to_512))
+    }
+
+    /// Re-emits the frame exactly as it was parsed (same sequence, physical, Port-Address and data without any
+    /// padding), returning the number of bytes written.
+    ///
+    /// This allows bridges to forward a received frame to another network segment verbatim.
+    pub fn reserialize<S: ByteSink>(&self, sink: S) -> usize {
+        self.put(sink, self.data.len())
+    }
+
+    fn padded_data_len(&self, pad_to_512: bool) -> usize {
+        let even_len = self.data.len() + self.data.len() % 2;
+
+        if pad_to_512 {
+            even_len.max(512)
+        } else {
+            even_len
+        }
+    }
+
+    fn put<S: ByteSink>(&self, sink: S, data_len: usize) -> usize {
+        const ZEROS: [u8; 512] = [0; 512];
+
+        let mut buf = Counter::new(sink);
+
+        put_header(&mut buf, OP_DMX);
+        buf.put_u8(self.sequence);
+        buf.put_u8(self.physical);
+        put_port_address(&mut buf, &self.port_address);
+        buf.put_u16(data_len as u16);
+        buf.put_slice(self.data);
+        buf.put_slice(&ZEROS[..data_len - self.data.len()]);
+
+        buf.written()
+    }
+}
+
 fn parse_sync<'a>(s: &'a [u8]) -> Result<(), Error<'a>> {
     let (s, _aux1) = number::u8(s)?;
     let (_s, _aux2) = number::u8(s)?;
 
     Ok(())
 }
+
+/// Serializes an ArtSync into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+pub fn serialize_sync<S: ByteSink>(sink: S) -> usize {
+    let mut buf = Counter::new(sink);
+
+    put_header(&mut buf, OP_SYNC);
+    // Aux1 and Aux2
+    buf.put_slice(&[0u8; 2]);
+
+    buf.written()
+}
+
+/// Serializes an ArtSync into the provided buffer, returning an error instead of panicking if the buffer is too small.
+pub fn try_serialize_sync(buf: &mut [u8]) -> Result<usize, SerializeError> {
+    ensure_capacity!(buf, SYNC_LEN);
+
+    Ok(serialize_sync(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_next_packet_skips_leading_junk() {
+        let mut buf = [0u8; 64];
+        buf[..5].copy_from_slice(b"junk!");
+        let poll_len = Poll::default().serialize(&mut buf[5..]);
+        let sync_len = serialize_sync(&mut buf[5 + poll_len..]);
+        let buf = &buf[..5 + poll_len + sync_len];
+
+        match find_next_packet(buf) {
+            Some((5, Art::Poll(poll))) => {
+                assert_eq!(poll.target_port_addresses, 0..=u16::MAX);
+                assert_eq!(poll.esta_filter, None);
+                assert_eq!(poll.oem_filter, None);
+            }
+            msg => panic!("Expected an ArtPoll at offset 5 but found: {:?}", msg),
+        };
+    }
+
+    #[test]
+    fn find_next_packet_does_not_read_into_the_next_packet() {
+        // A 14 byte Art-Net 3 poll without the targeting fields followed by an ArtSync
+        let mut buf = [0u8; 64];
+        buf[..ID.len()].copy_from_slice(ID);
+        buf[8..14].copy_from_slice(&[0x00, 0x20, 0, 14, 0b0000_0010, 0]);
+        let sync_len = serialize_sync(&mut buf[14..]);
+        let buf = &buf[..14 + sync_len];
+
+        match find_next_packet(buf) {
+            Some((0, Art::Poll(poll))) => {
+                assert_eq!(poll.flags, 0b0000_0010);
+                assert_eq!(poll.target_port_addresses, 0..=u16::MAX);
+            }
+            msg => panic!("Expected an ArtPoll at offset 0 but found: {:?}", msg),
+        };
+
+        match find_next_packet(&buf[ID.len()..]) {
+            Some((6, Art::Sync)) => {}
+            msg => panic!("Expected an ArtSync at offset 6 but found: {:?}", msg),
+        };
+    }
+
+    #[test]
+    fn len_hint_matches_the_serialized_length() {
+        let mut buf = [0u8; 600];
+
+        let poll = Poll {
+            oem_filter: Some(0x1234),
+            ..Default::default()
+        };
+        for poll in [Poll::default(), poll] {
+            let len = poll.serialize(&mut buf[..]);
+            assert_eq!(Art::Poll(poll).len_hint(), Some(len));
+        }
+
+        for data in [&[1, 2, 3][..], &[0; 512][..]] {
+            let dmx = Dmx::new(PortAddress::default(), data);
+            let len = dmx.serialize(&mut buf[..], false);
+            assert_eq!(Art::Dmx(dmx).len_hint(), Some(len));
+        }
+    }
+
+    #[test]
+    fn strict_mode_rejects_the_reserved_port_address_bit() {
+        let mut buf = [0u8; 32];
+        let len = Dmx::new(PortAddress::from_index(0x0123).unwrap(), &[1, 2])
+            .serialize(&mut buf[..], false);
+        // Set the reserved top bit of the Net byte
+        buf[15] |= 0x80;
+        let buf = &buf[..len];
+
+        let strict = ParseOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            from_slice_with_options(buf, &strict),
+            Err(Error::ParserError(_))
+        ));
+
+        match from_slice(buf) {
+            Ok(Art::Dmx(dmx)) => assert_eq!(dmx.universe(), 0x0123),
+            msg => panic!("Expected an ArtDmx but found: {:?}", msg),
+        };
+    }
+
+    #[test]
+    fn port_address_range_crosses_sub_net_boundaries() {
+        let start = PortAddress {
+            net: 1,
+            sub_net: 0x0F,
+            universe: 14,
+        };
+        let end = PortAddress {
+            net: 2,
+            sub_net: 0,
+            universe: 1,
+        };
+
+        let mut range = PortAddress::range(start, end);
+
+        for (net, sub_net, universe) in [(1, 0x0F, 14), (1, 0x0F, 15), (2, 0, 0), (2, 0, 1)] {
+            assert_eq!(
+                range.next(),
+                Some(PortAddress {
+                    net,
+                    sub_net,
+                    universe
+                })
+            );
+        }
+        assert_eq!(range.next(), None);
+    }
+
+    #[test]
+    fn port_address_range_stops_at_the_end_of_the_address_space() {
+        let start = PortAddress::from_index(PortAddress::MAX_INDEX - 1).unwrap();
+        let end = PortAddress {
+            net: 0xFF,
+            sub_net: 0x0F,
+            universe: 0x0F,
+        };
+
+        assert_eq!(PortAddress::range(start, end).count(), 2);
+        assert_eq!(PortAddress::range(end, start).count(), 0);
+    }
+
+    #[test]
+    fn lenient_parsing_clamps_a_short_dmx_frame() {
+        let data = [0x55; 512];
+        let mut buf = [0u8; DMX_HEADER_LEN + 512];
+        Dmx::new(PortAddress::default(), &data).serialize(&mut buf[..], false);
+        // The length field still declares 512 channels but only 500 were received
+        let buf = &buf[..DMX_HEADER_LEN + 500];
+
+        match from_slice(buf) {
+            Ok(Art::Dmx(dmx)) => {
+                assert!(dmx.truncated);
+                assert_eq!(dmx.data, &data[..500]);
+            }
+            msg => panic!("Expected a truncated ArtDmx but found: {:?}", msg),
+        };
+
+        let strict = ParseOptions {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            from_slice_with_options(buf, &strict),
+            Err(Error::ParserError(_))
+        ));
+    }
+
+    #[test]
+    fn dmx_pads_to_a_full_universe() {
+        let data = [0xAA; 24];
+        let dmx = Dmx::new(PortAddress::default(), &data);
+        let mut buf = [0xFF; DMX_HEADER_LEN + 512];
+
+        let len = dmx.serialize(&mut buf[..], true);
+
+        assert_eq!(len, DMX_HEADER_LEN + 512);
+        assert_eq!(&buf[16..18], &512u16.to_be_bytes());
+        assert_eq!(&buf[DMX_HEADER_LEN..DMX_HEADER_LEN + 24], &data);
+        assert!(buf[DMX_HEADER_LEN + 24..].iter().all(|value| *value == 0));
+
+        assert_eq!(dmx.serialize(&mut buf[..], false), DMX_HEADER_LEN + 24);
+    }
+
+    /// Asserts that serializing into a buffer one byte shorter than `len` returns `BufferTooSmall` rather than
+    /// panicking, and that a buffer of exactly `len` bytes succeeds.
+    fn assert_one_byte_short_errors(
+        len: usize,
+        mut try_serialize: impl FnMut(&mut [u8]) -> Result<usize, SerializeError>,
+    ) {
+        let mut buf = [0u8; 1024];
+
+        match try_serialize(&mut buf[..len - 1]) {
+            Err(SerializeError::BufferTooSmall { needed }) => assert_eq!(needed, len),
+            result => panic!("Expected BufferTooSmall but received: {:?}", result),
+        };
+
+        assert_eq!(try_serialize(&mut buf[..len]).unwrap(), len);
+    }
+
+    #[test]
+    fn serializers_error_on_a_one_byte_short_buffer() {
+        let port_address = PortAddress::from_index(7).unwrap();
+        let data = [1, 2, 3];
+
+        let poll = Poll::default();
+        assert_one_byte_short_errors(poll.serialized_len(), |buf| poll.try_serialize(buf));
+
+        let reply = PollReply::default();
+        assert_one_byte_short_errors(reply.serialized_len(), |buf| reply.try_serialize(buf));
+
+        let command = Command::new(ESTA_BROADCAST, b"SwoutText=Playback&");
+        assert_one_byte_short_errors(command.serialized_len(true), |buf| {
+            command.try_serialize(buf, true)
+        });
+
+        let dmx = Dmx::new(port_address, &data);
+        for pad_to_512 in [false, true] {
+            assert_one_byte_short_errors(dmx.serialized_len(pad_to_512), |buf| {
+                dmx.try_serialize(buf, pad_to_512)
+            });
+        }
+
+        assert_one_byte_short_errors(SYNC_LEN, try_serialize_sync);
+
+        let nzs = Nzs::new(0x17, port_address, &data);
+        assert_one_byte_short_errors(nzs.serialized_len(), |buf| nzs.try_serialize(buf));
+
+        let rdm = Rdm::new(port_address, &data);
+        assert_one_byte_short_errors(rdm.serialized_len(), |buf| rdm.try_serialize(buf));
+
+        let tod_control = TodControl::new(port_address, TodControlCommand::Flush);
+        assert_one_byte_short_errors(tod_control.serialized_len(), |buf| {
+            tod_control.try_serialize(buf)
+        });
+
+        let tod_request = TodRequest::new(0, &data);
+        assert_one_byte_short_errors(tod_request.serialized_len(), |buf| {
+            tod_request.try_serialize(buf)
+        });
+
+        let tod_data = TodData {
+            port: 1,
+            bind_index: 1,
+            port_address,
+            nak: false,
+            uid_total: 1,
+            block_count: 0,
+            uids: &[1, 2, 3, 4, 5, 6],
+        };
+        assert_one_byte_short_errors(tod_data.serialized_len(), |buf| tod_data.try_serialize(buf));
+
+        let input = Input::default();
+        assert_one_byte_short_errors(input.serialized_len(), |buf| input.try_serialize(buf));
+
+        let firmware_reply = FirmwareReply {
+            reply_type: FirmwareReplyType::AllGood,
+        };
+        assert_one_byte_short_errors(firmware_reply.serialized_len(), |buf| {
+            firmware_reply.try_serialize(buf)
+        });
+
+        let diag_data = DiagData::new(DiagnosticPriority::Low, 0, "Diagnostics");
+        assert_one_byte_short_errors(diag_data.serialized_len(), |buf| {
+            diag_data.try_serialize(buf)
+        });
+
+        let directory = Directory::new(0, 0);
+        assert_one_byte_short_errors(directory.serialized_len(), |buf| {
+            directory.try_serialize(buf)
+        });
+
+        let directory_reply = DirectoryReply {
+            flags: 0,
+            file: 1,
+            name: "show.bin",
+            description: "Show file",
+            length: 1024,
+            data: &[0; 64],
+        };
+        assert_one_byte_short_errors(directory_reply.serialized_len(), |buf| {
+            directory_reply.try_serialize(buf)
+        });
+    }
+
+    fn assert_parses_to(op_code: OpCode, packet: &[u8]) {
+        match from_slice(packet) {
+            Ok(art) => assert_eq!(art.op_code(), op_code),
+            Err(err) => panic!("Expected {:?} but received: {:?}", op_code, err),
+        };
+    }
+
+    #[test]
+    fn every_supported_op_code_parses_to_its_variant() {
+        let port_address = PortAddress::from_index(7).unwrap();
+        let data = [1, 2, 3];
+        let mut buf = [0u8; 1024];
+
+        let len = Poll::default().serialize(&mut buf[..]);
+        assert_parses_to(OpCode::Poll, &buf[..len]);
+
+        let len = PollReply::default().serialize(&mut buf[..]);
+        assert_parses_to(OpCode::PollReply, &buf[..len]);
+
+        let len =
+            Command::new(ESTA_BROADCAST, b"SwoutText=Playback&").serialize(&mut buf[..], true);
+        assert_parses_to(OpCode::Command, &buf[..len]);
+
+        let len = Dmx::new(port_address, &data).serialize(&mut buf[..], false);
+        assert_parses_to(OpCode::Dmx, &buf[..len]);
+
+        let len = Nzs::new(0x17, port_address, &data).serialize(&mut buf[..]);
+        assert_parses_to(OpCode::Nzs, &buf[..len]);
+
+        let len = serialize_sync(&mut buf[..]);
+        assert_parses_to(OpCode::Sync, &buf[..len]);
+
+        let len = TodControl::new(port_address, TodControlCommand::Flush).serialize(&mut buf[..]);
+        assert_parses_to(OpCode::TodControl, &buf[..len]);
+
+        let len = TodRequest::new(0, &data).serialize(&mut buf[..]);
+        assert_parses_to(OpCode::TodRequest, &buf[..len]);
+
+        let tod_data = TodData {
+            port: 1,
+            bind_index: 1,
+            port_address,
+            nak: false,
+            uid_total: 1,
+            block_count: 0,
+            uids: &[1, 2, 3, 4, 5, 6],
+        };
+        let len = tod_data.serialize(&mut buf[..]);
+        assert_parses_to(OpCode::TodData, &buf[..len]);
+
+        let len = Rdm::new(port_address, &[0x01, 0x18, 0x00]).serialize(&mut buf[..]);
+        assert_parses_to(OpCode::Rdm, &buf[..len]);
+
+        let len = Input::default().serialize(&mut buf[..]);
+        assert_parses_to(OpCode::Input, &buf[..len]);
+
+        let firmware_reply = FirmwareReply {
+            reply_type: FirmwareReplyType::AllGood,
+        };
+        let len = firmware_reply.serialize(&mut buf[..]);
+        assert_parses_to(OpCode::FirmwareReply, &buf[..len]);
+
+        let len = DiagData::new(DiagnosticPriority::Low, 0, "Diagnostics").serialize(&mut buf[..]);
+        assert_parses_to(OpCode::DiagData, &buf[..len]);
+
+        let len = Directory::new(0, 0).serialize(&mut buf[..]);
+        assert_parses_to(OpCode::Directory, &buf[..len]);
+
+        let directory_reply = DirectoryReply {
+            flags: 0,
+            file: 1,
+            name: "show.bin",
+            description: "Show file",
+            length: 1024,
+            data: &[0; 64],
+        };
+        let len = directory_reply.serialize(&mut buf[..]);
+        assert_parses_to(OpCode::DirectoryReply, &buf[..len]);
+
+        // ArtAddress and ArtRdmSub cannot be serialized so an all zero body (no changes / no sub-devices) is used
+        for (op_code, len) in [
+            (OpCode::Address, address::ADDRESS_LEN),
+            (OpCode::RdmSub, rdm_sub::RDM_SUB_HEADER_LEN),
+        ] {
+            let mut buf = [0u8; 128];
+            put_header(&mut &mut buf[..], op_code.as_u16());
+            assert_parses_to(op_code, &buf[..len]);
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use core::{fmt, ops::Deref};
+
+/// A fixed capacity string for building names at runtime without an allocator, eg. a `Name<18>` for a PollReply's
+/// short name or a `Name<64>` for its long name.
+///
+/// Names deref to `&str` so they can be used wherever a `&str` is expected. Text written beyond the capacity is
+/// truncated.
+#[derive(Clone)]
+pub struct Name<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Name<N> {
+    pub fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only whole chars are ever copied into the buffer
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl<const N: usize> Default for Name<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for Name<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Write for Name<N> {
+    /// Appends as much of the string as will fit, returning an error if it was truncated.
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let remaining = N - self.len;
+
+        // Truncate on a char boundary so the name remains valid UTF-8
+        let mut len = s.len().min(remaining);
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        self.bytes[self.len..self.len + len].copy_from_slice(&s.as_bytes()[..len]);
+        self.len += len;
+
+        if len < s.len() {
+            return Err(fmt::Error);
+        }
+
+        Ok(())
+    }
+}
+
+impl<const N: usize> From<&str> for Name<N> {
+    /// Copies the string, truncating it to the name's capacity.
+    fn from(s: &str) -> Self {
+        let mut name = Self::new();
+        let _ = fmt::Write::write_str(&mut name, s);
+        name
+    }
+}
+
+impl<const N: usize> fmt::Debug for Name<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Display for Name<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,304 @@
+use crate::{Address, AddressCommand, ByteSink, Dmx, IndicatorState, Poll, PollReply};
+
+/// ArtPoll flags bit 1: set if the controller wants an ArtPollReply whenever the node's conditions change
+const POLL_FLAG_REPLY_ON_CHANGE: u8 = 0b0000_0010;
+/// ArtPoll flags bit 3: set if the controller wants messages unicast to it rather than broadcast
+const POLL_FLAG_UNICAST: u8 = 0b0000_1000;
+/// ArtPoll flags bit 5: set if only nodes with a Port-Address in the poll's target range should reply
+const POLL_FLAG_TARGETED: u8 = 0b0010_0000;
+
+/// Where a node should send its ArtPollReply. See `reply_destination`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplyDestination {
+    /// Send the reply to the `PORT` of this IP address
+    Unicast([u8; 4]),
+    /// Broadcast the reply (see `broadcast_reply`)
+    Broadcast,
+}
+
+/// Decides where to send the ArtPollReply answering the `poll` received from the `from` IP address.
+///
+/// Controllers that set bit 3 of the poll's `flags` ask for messages to be unicast to them, otherwise (eg. Art-Net 3
+/// controllers) the reply is broadcast so that every controller on the network sees it. The reply is also broadcast
+/// when the sender's address is unusable, ie. unspecified (0.0.0.0) or the limited broadcast address.
+pub fn reply_destination(poll: &Poll, from: [u8; 4]) -> ReplyDestination {
+    match from {
+        [0, 0, 0, 0] | [255, 255, 255, 255] => ReplyDestination::Broadcast,
+        _ if poll.flags & POLL_FLAG_UNICAST == 0 => ReplyDestination::Broadcast,
+        ip_address => ReplyDestination::Unicast(ip_address),
+    }
+}
+
+/// Returns true if the controller that sent the `poll` wants the node to send an unsolicited ArtPollReply whenever
+/// its conditions change, rather than only in response to ArtPolls.
+pub fn reply_on_change(poll: &Poll) -> bool {
+    poll.flags & POLL_FLAG_REPLY_ON_CHANGE != 0
+}
+
+/// Helpers implementing the behaviour required of an Art-Net node.
+#[derive(Debug)]
+pub struct Node<'a> {
+    /// The ArtPollReply this node advertises itself with.
+    pub reply: PollReply<'a>,
+}
+
+impl<'a> Node<'a> {
+    pub fn new(reply: PollReply<'a>) -> Self {
+        Self { reply }
+    }
+
+    /// Serializes the unsolicited ArtPollReply a node must send when it starts up (in addition to replying to
+    /// ArtPolls), returning the number of bytes written.
+    ///
+    /// Per the spec this reply should be broadcast to the `PORT` of either the directed broadcast address of the
+    /// node's subnet (eg. 2.255.255.255) or the limited broadcast address (255.255.255.255).
+    pub fn startup_reply<S: ByteSink>(&self, buf: S) -> usize {
+        self.reply.serialize(buf)
+    }
+
+    /// Returns true if the node should reply to the `poll`.
+    ///
+    /// Controllers using targeted mode (bit 5 of `flags`) only want replies from nodes with an input or output
+    /// Port-Address in the poll's `target_port_addresses`, and the manufacturer and Oem filters only want replies from
+    /// matching nodes. Art-Net 3 polls do not contain a target range so targeted mode matches every node.
+    pub fn should_reply(&self, poll: &Poll) -> bool {
+        if let Some(esta_filter) = poll.esta_filter {
+            if esta_filter != self.reply.esta_manufacturer_code {
+                return false;
+            }
+        }
+
+        if let Some(oem_filter) = poll.oem_filter {
+            if oem_filter != self.reply.oem {
+                return false;
+            }
+        }
+
+        if poll.flags & POLL_FLAG_TARGETED == 0 {
+            return true;
+        }
+
+        self.reply
+            .input_port_addresses()
+            .chain(self.reply.output_port_addresses())
+            .any(|(_port, port_address)| {
+                poll.target_port_addresses
+                    .contains(&(port_address.as_index() as u16))
+            })
+    }
+
+    /// Returns the index of the node's output port that the ArtDmx is addressed to, or None if it is not addressed to
+    /// any of this node's outputs.
+    ///
+    /// Output ports are those with the output bit (bit 7) of their `port_types` set. Their Port-Address is composed
+    /// of the reply's `net_switch` (if bit 3 of `status2` advertises 15-bit Port-Address support), `sub_switch` and
+    /// their `swout` universe.
+    pub fn output_index(&self, dmx: &Dmx) -> Option<usize> {
+        self.reply
+            .output_port_addresses()
+            .find(|(_port, port_address)| *port_address == dmx.port_address)
+            .map(|(port, _port_address)| port)
+    }
+
+    /// Applies the Port-Address switches, sACN priority, indicator (AcLed) and failsafe (AcFail) commands programmed
+    /// by an ArtAddress to the node's reply, leaving the fields the controller did not change untouched.
+    ///
+    /// Note: The names, per-port swin / swout values and per-port `good_output_a` flags (eg. the merge mode) are
+    /// borrowed by the reply so they, and any other commands, must be handled by the caller.
+    pub fn apply_address(&mut self, address: &Address) {
+        if let Some(net_switch) = address.net_switch {
+            self.reply.net_switch = net_switch;
+        }
+
+        if let Some(sub_switch) = address.sub_switch {
+            self.reply.sub_switch = sub_switch & 0x0F;
+        }
+
+        if let Some(acn_priority) = address.acn_priority {
+            self.reply.acn_priority = acn_priority;
+        }
+
+        let indicator_state = match address.command {
+            AddressCommand::LedNormal => Some(IndicatorState::Normal),
+            AddressCommand::LedMute => Some(IndicatorState::Mute),
+            AddressCommand::LedLocate => Some(IndicatorState::Locate),
+            _ => None,
+        };
+
+        if let Some(indicator_state) = indicator_state {
+            self.reply.status1 =
+                (self.reply.status1 & 0b0011_1111) | indicator_state.as_status1_bits();
+        }
+
+        // The failsafe state is reported in bits 7-6 of status3
+        let failsafe_bits = match address.command {
+            AddressCommand::FailHold => Some(0b00),
+            AddressCommand::FailZero => Some(0b01),
+            AddressCommand::FailFull => Some(0b10),
+            AddressCommand::FailScene => Some(0b11),
+            _ => None,
+        };
+
+        if let Some(failsafe_bits) = failsafe_bits {
+            self.reply.status3 = (self.reply.status3 & 0b0011_1111) | (failsafe_bits << 6);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reply_destination_follows_the_unicast_flag() {
+        let unicast_poll = Poll {
+            flags: POLL_FLAG_UNICAST,
+            ..Default::default()
+        };
+        let broadcast_poll = Poll::default();
+
+        assert_eq!(
+            reply_destination(&unicast_poll, [10, 0, 0, 5]),
+            ReplyDestination::Unicast([10, 0, 0, 5])
+        );
+        assert_eq!(
+            reply_destination(&broadcast_poll, [10, 0, 0, 5]),
+            ReplyDestination::Broadcast
+        );
+
+        for from in [[0, 0, 0, 0], [255, 255, 255, 255]] {
+            assert_eq!(
+                reply_destination(&unicast_poll, from),
+                ReplyDestination::Broadcast
+            );
+        }
+    }
+
+    const CONTROLLER_IP: [u8; 4] = [10, 0, 0, 1];
+    /// The target range of the Art-Net 4 polls, SubNet 1 of Net 0
+    const TARGET_PORT_ADDRESSES: core::ops::RangeInclusive<u16> = 0x0010..=0x001F;
+
+    /// A row of the ArtPoll reply decision table
+    struct Case {
+        /// Art-Net 4 polls include a target range, Art-Net 3 polls end after the diagnostics priority
+        art_net_4: bool,
+        flags: u8,
+        /// Whether a node outputting universe 0x1FF (outside of the target range) replies
+        outside_target_replies: bool,
+        reply_on_change: bool,
+        destination: ReplyDestination,
+    }
+
+    const UNICAST: ReplyDestination = ReplyDestination::Unicast(CONTROLLER_IP);
+    const BROADCAST: ReplyDestination = ReplyDestination::Broadcast;
+
+    #[rustfmt::skip]
+    const CASES: [Case; 16] = [
+        Case { art_net_4: false, flags: 0b0000_0000, outside_target_replies: true, reply_on_change: false, destination: BROADCAST },
+        Case { art_net_4: false, flags: 0b0000_0010, outside_target_replies: true, reply_on_change: true, destination: BROADCAST },
+        Case { art_net_4: false, flags: 0b0000_1000, outside_target_replies: true, reply_on_change: false, destination: UNICAST },
+        Case { art_net_4: false, flags: 0b0000_1010, outside_target_replies: true, reply_on_change: true, destination: UNICAST },
+        Case { art_net_4: false, flags: 0b0010_0000, outside_target_replies: true, reply_on_change: false, destination: BROADCAST },
+        Case { art_net_4: false, flags: 0b0010_0010, outside_target_replies: true, reply_on_change: true, destination: BROADCAST },
+        Case { art_net_4: false, flags: 0b0010_1000, outside_target_replies: true, reply_on_change: false, destination: UNICAST },
+        Case { art_net_4: false, flags: 0b0010_1010, outside_target_replies: true, reply_on_change: true, destination: UNICAST },
+        Case { art_net_4: true, flags: 0b0000_0000, outside_target_replies: true, reply_on_change: false, destination: BROADCAST },
+        Case { art_net_4: true, flags: 0b0000_0010, outside_target_replies: true, reply_on_change: true, destination: BROADCAST },
+        Case { art_net_4: true, flags: 0b0000_1000, outside_target_replies: true, reply_on_change: false, destination: UNICAST },
+        Case { art_net_4: true, flags: 0b0000_1010, outside_target_replies: true, reply_on_change: true, destination: UNICAST },
+        Case { art_net_4: true, flags: 0b0010_0000, outside_target_replies: false, reply_on_change: false, destination: BROADCAST },
+        Case { art_net_4: true, flags: 0b0010_0010, outside_target_replies: false, reply_on_change: true, destination: BROADCAST },
+        Case { art_net_4: true, flags: 0b0010_1000, outside_target_replies: false, reply_on_change: false, destination: UNICAST },
+        Case { art_net_4: true, flags: 0b0010_1010, outside_target_replies: false, reply_on_change: true, destination: UNICAST },
+    ];
+
+    /// A node with a single output port on the Port-Address net:sub_switch:swout
+    fn node(net_switch: u8, sub_switch: u8, swout: &[u8; 4]) -> Node<'_> {
+        Node::new(PollReply {
+            net_switch,
+            sub_switch,
+            num_ports: 1,
+            port_types: &[0b1000_0000, 0, 0, 0],
+            swout,
+            ..Default::default()
+        })
+    }
+
+    /// Serializes the poll the way a controller of the case's Art-Net version would and parses it back
+    fn parse_poll(case: &Case, buf: &mut [u8]) -> Poll {
+        let len = if case.art_net_4 {
+            let poll = Poll {
+                flags: case.flags,
+                target_port_addresses: TARGET_PORT_ADDRESSES,
+                ..Default::default()
+            };
+            poll.serialize(&mut buf[..])
+        } else {
+            buf[..8].copy_from_slice(b"Art-Net\0");
+            buf[8..14].copy_from_slice(&[0x00, 0x20, 0, 14, case.flags, 0]);
+            14
+        };
+
+        match crate::from_slice(&buf[..len]) {
+            Ok(crate::Art::Poll(poll)) => poll,
+            msg => panic!("Expected an ArtPoll but received: {:?}", msg),
+        }
+    }
+
+    #[test]
+    fn poll_reply_decision_table() {
+        let inside_target = node(0, 1, &[2, 0, 0, 0]);
+        let outside_target = node(1, 0xF, &[0xF, 0, 0, 0]);
+
+        for case in &CASES {
+            let mut buf = [0u8; 64];
+            let poll = parse_poll(case, &mut buf);
+
+            assert!(
+                inside_target.should_reply(&poll),
+                "flags {:#010b}",
+                case.flags
+            );
+            assert_eq!(
+                outside_target.should_reply(&poll),
+                case.outside_target_replies,
+                "Art-Net 4: {}, flags {:#010b}",
+                case.art_net_4,
+                case.flags
+            );
+            assert_eq!(reply_on_change(&poll), case.reply_on_change);
+            assert_eq!(reply_destination(&poll, CONTROLLER_IP), case.destination);
+        }
+    }
+
+    #[test]
+    fn answering_a_poll_postpones_the_next_keep_alive() {
+        let inside_target = node(0, 1, &[2, 0, 0, 0]);
+        let outside_target = node(1, 0xF, &[0xF, 0, 0, 0]);
+        let targeted = &CASES[12];
+
+        for (node, replies) in [(&inside_target, true), (&outside_target, false)] {
+            let mut scheduler = crate::ReplyScheduler::new(1000);
+
+            // The startup reply is sent immediately and parses back to the node's reply
+            assert!(scheduler.should_reply(0));
+            let mut buf = [0u8; 512];
+            let len = node.startup_reply(&mut buf[..]);
+            match crate::from_slice(&buf[..len]) {
+                Ok(crate::Art::PollReply(reply)) => {
+                    assert!(reply.eq_ignoring_volatile(&node.reply))
+                }
+                msg => panic!("Expected an ArtPollReply but received: {:?}", msg),
+            };
+
+            let poll = parse_poll(targeted, &mut buf);
+            assert_eq!(node.should_reply(&poll), replies);
+            if node.should_reply(&poll) {
+                scheduler.replied(900);
+            }
+
+            // Only a node that did not answer the poll is due a keep-alive one interval after starting up
+            assert_eq!(scheduler.should_reply(1000), !replies);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use core::fmt::{self, Write};
+
+/// The maximum length of a node report, leaving room for the Nul terminator in the 64 byte field
+const MAX_NODE_REPORT_LEN: usize = 63;
+
+/// The status codes reported at the start of an ArtPollReply's `node_report`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u16)]
+pub enum NodeReportCode {
+    /// RcDebug: Booted in debug mode (only used in development)
+    Debug = 0x0000,
+    /// RcPowerOk: Power on tests successful
+    PowerOk = 0x0001,
+    /// RcPowerFail: Hardware tests failed at power on
+    PowerFail = 0x0002,
+    /// RcSocketWr1: Last UDP from the node failed due to a truncated length
+    SocketWr1 = 0x0003,
+    /// RcParseFail: Unable to identify the last UDP transmission
+    ParseFail = 0x0004,
+    /// RcUdpFail: Unable to open a UDP socket in the last transmission attempt
+    UdpFail = 0x0005,
+    /// RcShNameOk: Short name programming via ArtAddress was successful
+    ShortNameOk = 0x0006,
+    /// RcLoNameOk: Long name programming via ArtAddress was successful
+    LongNameOk = 0x0007,
+    /// RcDmxError: DMX512 receive errors detected
+    DmxError = 0x0008,
+    /// RcDmxUdpFull: Ran out of internal DMX transmit buffers
+    DmxUdpFull = 0x0009,
+    /// RcDmxRxFull: Ran out of internal DMX receive buffers
+    DmxRxFull = 0x000A,
+    /// RcSwitchErr: Rx universe switches conflict
+    SwitchError = 0x000B,
+    /// RcConfigErr: The product configuration does not match the firmware
+    ConfigError = 0x000C,
+    /// RcDmxShort: DMX output short detected. See `good_output_a`.
+    DmxShort = 0x000D,
+    /// RcFirmwareFail: The last attempt to upload new firmware failed
+    FirmwareFail = 0x000E,
+    /// RcUserFail: The user changed the switch settings when address locked by remote programming
+    UserFail = 0x000F,
+    /// RcFactoryRes: Factory reset has occurred
+    FactoryReset = 0x0010,
+}
+
+impl NodeReportCode {
+    pub fn from_u16(code: u16) -> Option<NodeReportCode> {
+        let code = match code {
+            0x0000 => NodeReportCode::Debug,
+            0x0001 => NodeReportCode::PowerOk,
+            0x0002 => NodeReportCode::PowerFail,
+            0x0003 => NodeReportCode::SocketWr1,
+            0x0004 => NodeReportCode::ParseFail,
+            0x0005 => NodeReportCode::UdpFail,
+            0x0006 => NodeReportCode::ShortNameOk,
+            0x0007 => NodeReportCode::LongNameOk,
+            0x0008 => NodeReportCode::DmxError,
+            0x0009 => NodeReportCode::DmxUdpFull,
+            0x000A => NodeReportCode::DmxRxFull,
+            0x000B => NodeReportCode::SwitchError,
+            0x000C => NodeReportCode::ConfigError,
+            0x000D => NodeReportCode::DmxShort,
+            0x000E => NodeReportCode::FirmwareFail,
+            0x000F => NodeReportCode::UserFail,
+            0x0010 => NodeReportCode::FactoryReset,
+            _ => return None,
+        };
+
+        Some(code)
+    }
+
+    pub fn as_u16(&self) -> u16 {
+        *self as u16
+    }
+}
+
+impl TryFrom<u16> for NodeReportCode {
+    /// The unknown code
+    type Error = u16;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        NodeReportCode::from_u16(value).ok_or(value)
+    }
+}
+
+/// A node report decoded from the spec's "#xxxx [yyyy] zzzzz..." format, where xxxx is the hex status code, yyyy
+/// is a decimal counter incremented with every ArtPollReply and zzzzz is free text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeReport<'a> {
+    /// The status code. See `NodeReportCode::from_u16`.
+    pub code: u16,
+    pub counter: u16,
+    pub text: &'a str,
+}
+
+impl<'a> NodeReport<'a> {
+    /// Decodes a node report, returning None if it is not in the "#xxxx [yyyy] zzzzz..." format.
+    pub fn parse(report: &'a str) -> Option<NodeReport<'a>> {
+        let report = report.strip_prefix('#')?;
+        let code = u16::from_str_radix(report.get(..4)?, 16).ok()?;
+        let report = report.get(4..)?.strip_prefix(" [")?;
+        let (counter, text) = report.split_once(']')?;
+
+        Some(NodeReport {
+            code,
+            counter: counter.parse().ok()?,
+            text: text.strip_prefix(' ').unwrap_or(text),
+        })
+    }
+
+    /// Formats the node report into the buffer, returning the formatted report. Reports longer than the 63 bytes
+    /// that fit in an ArtPollReply are truncated.
+    pub fn format<'b>(&self, buf: &'b mut [u8; 64]) -> &'b str {
+        let mut writer = TruncatingWriter {
+            buf: &mut buf[..MAX_NODE_REPORT_LEN],
+            len: 0,
+            full: false,
+        };
+        // The writer truncates rather than returning errors
+        let _ = write!(
+            writer,
+            "#{:04x} [{:04}] {}",
+            self.code, self.counter, self.text
+        );
+
+        let len = writer.len;
+        // Only whole characters are written so the report is always valid UTF-8
+        core::str::from_utf8(&buf[..len]).unwrap_or_default()
+    }
+}
+
+/// Writes as many whole characters as fit in the buffer, silently dropping the rest.
+struct TruncatingWriter<'b> {
+    buf: &'b mut [u8],
+    len: usize,
+    /// Set once a character has been dropped so that no later (shorter) characters are written after the gap
+    full: bool,
+}
+
+impl<'b> Write for TruncatingWriter<'b> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let end = self.len + c.len_utf8();
+            if self.full || end > self.buf.len() {
+                self.full = true;
+                break;
+            }
+
+            c.encode_utf8(&mut self.buf[self.len..end]);
+            self.len = end;
+        }
+
+        Ok(())
+    }
+}
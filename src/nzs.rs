@@ -0,0 +1,90 @@
+use nom::{bytes::complete::take, number::complete as number};
+
+use crate::{
+    parse_port_address, put_header, put_port_address, read_dmx_length, sink::Counter, ByteSink,
+    ParseOptions, PortAddress, SerializeError,
+};
+
+pub(crate) const OP_NZS: u16 = 0x5100;
+
+/// Length of an ArtNzs packet excluding the data
+pub(crate) const NZS_HEADER_LEN: usize = 18;
+
+/// ArtNzs carries DMX512 data with a non-zero start code (except RDM) for a single universe.
+#[derive(Debug, Default)]
+pub struct Nzs<'a> {
+    /// Incremented in the range 0x01 to 0xff to allow the receiving node to re-sequence packets. 0x00 disables
+    /// sequencing. See `Dmx::sequence`.
+    pub sequence: u8,
+    /// The DMX512 start code of the data
+    pub start_code: u8,
+    pub port_address: PortAddress,
+    pub data: &'a [u8],
+}
+
+pub(crate) fn parse_nzs<'a>(
+    s: &'a [u8],
+    options: &ParseOptions,
+) -> Result<Nzs<'a>, crate::Error<'a>> {
+    let (s, sequence) = number::u8(s)?;
+    let (s, start_code) = number::u8(s)?;
+    let (s, port_address) = parse_port_address(s, options.strict)?;
+    let (s, length) = read_dmx_length(s)?;
+    let (_s, data) = take(length)(s)?;
+
+    Ok(Nzs {
+        sequence,
+        start_code,
+        port_address,
+        data,
+    })
+}
+
+impl<'a> Nzs<'a> {
+    /// Creates an ArtNzs for the data with sequencing disabled.
+    pub fn new(start_code: u8, port_address: PortAddress, data: &'a [u8]) -> Self {
+        Self {
+            start_code,
+            port_address,
+            data,
+            ..Default::default()
+        }
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        NZS_HEADER_LEN + self.padded_data_len()
+    }
+
+    fn padded_data_len(&self) -> usize {
+        self.data.len() + self.data.len() % 2
+    }
+
+    /// Serializes the ArtNzs into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the ArtNzs into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    ///
+    /// Like ArtDmx the spec requires an even length so odd length data is padded with a trailing zero.
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+
+        let data_len = self.padded_data_len();
+
+        put_header(&mut buf, OP_NZS);
+        buf.put_u8(self.sequence);
+        buf.put_u8(self.start_code);
+        put_port_address(&mut buf, &self.port_address);
+        buf.put_u16(data_len as u16);
+        buf.put_slice(self.data);
+        if data_len > self.data.len() {
+            buf.put_u8(0);
+        }
+
+        buf.written()
+    }
+}
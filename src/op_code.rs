@@ -0,0 +1,147 @@
+/// The op-codes defined by the Art-Net 4 spec.
+///
+/// The discriminants are the op-code's wire values so the enum can be used across an FFI boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
+pub enum OpCode {
+    /// ArtPoll: Sent by controllers to discover nodes
+    Poll = 0x2000,
+    /// ArtPollReply: Sent by nodes in response to an ArtPoll
+    PollReply = 0x2100,
+    /// ArtDiagData: Diagnostics and data logging
+    DiagData = 0x2300,
+    /// ArtCommand: Text based parameter commands
+    Command = 0x2400,
+    /// ArtDataRequest: Requests data such as product URLs
+    DataRequest = 0x2700,
+    /// ArtDataReply: Replies to an ArtDataRequest
+    DataReply = 0x2800,
+    /// ArtDmx (also known as ArtOutput, OpOutput): Zero start code DMX512 data for a single universe
+    Dmx = 0x5000,
+    /// ArtNzs: Non-zero start code DMX512 data for a single universe
+    Nzs = 0x5100,
+    /// ArtSync: Forces synchronous output of ArtDmx packets
+    Sync = 0x5200,
+    /// ArtAddress: Remote programming of a node
+    Address = 0x6000,
+    /// ArtInput: Enables and disables DMX inputs
+    Input = 0x7000,
+    /// ArtTodRequest: Requests the RDM Table of Devices
+    TodRequest = 0x8000,
+    /// ArtTodData: Sends the RDM Table of Devices
+    TodData = 0x8100,
+    /// ArtTodControl: Sends RDM discovery control messages
+    TodControl = 0x8200,
+    /// ArtRdm: Sends all non-discovery RDM messages
+    Rdm = 0x8300,
+    /// ArtRdmSub: Sends compressed RDM sub-device data
+    RdmSub = 0x8400,
+    /// ArtMedia: Sent by media servers to controllers
+    Media = 0x9000,
+    /// ArtMediaPatch: Sent by controllers to media servers
+    MediaPatch = 0x9100,
+    /// ArtMediaControl: Sent by controllers to media servers
+    MediaControl = 0x9200,
+    /// ArtMediaControlReply: Sent by media servers to controllers
+    MediaControlReply = 0x9300,
+    /// ArtTimeCode: Transports time code
+    TimeCode = 0x9700,
+    /// ArtTimeSync: Synchronises real time date and clock
+    TimeSync = 0x9800,
+    /// ArtTrigger: Sends trigger macros
+    Trigger = 0x9900,
+    /// ArtDirectory: Requests a node's file list
+    Directory = 0x9A00,
+    /// ArtDirectoryReply: Replies to an ArtDirectory with a file list
+    DirectoryReply = 0x9B00,
+    /// ArtVideoSetup: Extended video features
+    VideoSetup = 0xA010,
+    /// ArtVideoPalette: Extended video features
+    VideoPalette = 0xA020,
+    /// ArtVideoData: Extended video features
+    VideoData = 0xA040,
+    /// Deprecated
+    MacMaster = 0xF000,
+    /// Deprecated
+    MacSlave = 0xF100,
+    /// ArtFirmwareMaster: Uploads firmware or UBEA to a node
+    FirmwareMaster = 0xF200,
+    /// ArtFirmwareReply: Acknowledges an ArtFirmwareMaster block
+    FirmwareReply = 0xF300,
+    /// Uploads user files to a node
+    FileTnMaster = 0xF400,
+    /// Downloads user files from a node
+    FileFnMaster = 0xF500,
+    /// Acknowledges a file download block
+    FileFnReply = 0xF600,
+    /// ArtIpProg: Reprograms a node's IP address
+    IpProg = 0xF800,
+    /// ArtIpProgReply: Replies to an ArtIpProg
+    IpProgReply = 0xF900,
+}
+
+const ALL: [OpCode; 37] = [
+    OpCode::Poll,
+    OpCode::PollReply,
+    OpCode::DiagData,
+    OpCode::Command,
+    OpCode::DataRequest,
+    OpCode::DataReply,
+    OpCode::Dmx,
+    OpCode::Nzs,
+    OpCode::Sync,
+    OpCode::Address,
+    OpCode::Input,
+    OpCode::TodRequest,
+    OpCode::TodData,
+    OpCode::TodControl,
+    OpCode::Rdm,
+    OpCode::RdmSub,
+    OpCode::Media,
+    OpCode::MediaPatch,
+    OpCode::MediaControl,
+    OpCode::MediaControlReply,
+    OpCode::TimeCode,
+    OpCode::TimeSync,
+    OpCode::Trigger,
+    OpCode::Directory,
+    OpCode::DirectoryReply,
+    OpCode::VideoSetup,
+    OpCode::VideoPalette,
+    OpCode::VideoData,
+    OpCode::MacMaster,
+    OpCode::MacSlave,
+    OpCode::FirmwareMaster,
+    OpCode::FirmwareReply,
+    OpCode::FileTnMaster,
+    OpCode::FileFnMaster,
+    OpCode::FileFnReply,
+    OpCode::IpProg,
+    OpCode::IpProgReply,
+];
+
+impl OpCode {
+    /// Returns None for op-codes not defined by the spec
+    pub fn from_u16(op_code: u16) -> Option<OpCode> {
+        ALL.iter().copied().find(|known| known.as_u16() == op_code)
+    }
+
+    /// The little endian u16 sent over the wire
+    pub fn as_u16(&self) -> u16 {
+        *self as u16
+    }
+
+    /// Iterates over every known op-code in ascending order, eg. for building complete statistics tables.
+    pub fn all() -> impl Iterator<Item = OpCode> {
+        ALL.into_iter()
+    }
+}
+
+impl TryFrom<u16> for OpCode {
+    /// The op-code not defined by the spec
+    type Error = u16;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        OpCode::from_u16(value).ok_or(value)
+    }
+}
@@ -1,9 +1,66 @@
-use bytes::BufMut;
+use core::fmt;
 
-use crate::put_esta_manufacturer_code;
+use nom::{
+    combinator::{opt, verify},
+    number::complete as number,
+    number::complete::be_u16,
+    sequence::tuple,
+};
 
-const OP_POLL_REPLY: u16 = 0x2100;
+use crate::{
+    parse_array, parse_esta_manufacturer_code, parse_padded_str, port_address_from_reply,
+    put_esta_manufacturer_code, sink::Counter, ByteSink, NodeReport, NodeReportCode, PortAddress,
+    SerializeError,
+};
 
+pub(crate) const OP_POLL_REPLY: u16 = 0x2100;
+
+/// The maximum number of ports described by a single ArtPollReply
+const MAX_PORTS: u16 = 4;
+
+/// Length of a serialized ArtPollReply, including the header and filler
+pub(crate) const POLL_REPLY_LEN: usize = 239;
+
+/// The `status2` bit set when a node supports 15-bit Port-Addresses
+const STATUS2_15_BIT_PORT_ADDRESS: u8 = 0b0000_1000;
+
+/// The sACN priority used when none is configured
+const DEFAULT_ACN_PRIORITY: u8 = 100;
+
+/// The highest valid sACN priority
+const MAX_ACN_PRIORITY: u8 = 200;
+
+/// Length of the filler at the end of an ArtPollReply available to `extra`
+pub(crate) const FILLER_LEN: usize = 15;
+
+/// Returned by `PollReply::set_ports` when the ports cannot be described by a single ArtPollReply.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MixedNetError {
+    /// The index of the first port which needs a separate reply page, ie. its Net or SubNet differs from the first
+    /// port's or it is beyond the 4 ports of a single reply.
+    pub port: usize,
+}
+
+/// ArtPollReply is sent by a node in response to an ArtPoll, describing the node and its ports.
+///
+/// The raw fields can be set directly, or from the high level enums:
+///
+/// ```
+/// use tiny_artnet::{IndicatorState, Oem, PollReply, Style};
+///
+/// let reply = PollReply {
+///     short_name: "Example Node",
+///     long_name: "Tiny Artnet Example Node",
+///     ..Default::default()
+/// }
+/// .with_style(Style::Node)
+/// .with_oem(Oem::Unknown)
+/// .with_indicator_state(IndicatorState::Normal);
+///
+/// assert_eq!(reply.device_style(), Some(Style::Node));
+/// assert_eq!(reply.oem_code(), Oem::Unknown);
+/// assert_eq!(reply.indicator_state(), IndicatorState::Normal);
+/// ```
 #[derive(Debug)]
 pub struct PollReply<'a> {
     pub ip_address: &'a [u8; 4],
@@ -47,12 +104,16 @@ pub struct PollReply<'a> {
     pub long_name: &'a str,
     /// Note: The spec specifies ASCII characters only
     pub node_report: &'a str,
+    /// The number of input or output ports (whichever is larger). Sent as a big endian u16 with a maximum value of 4.
     pub num_ports: u16,
     pub port_types: &'a [u8; 4],
     pub good_input: &'a [u8; 4],
     pub good_output_a: &'a [u8; 4],
     pub swin: &'a [u8; 4],
     pub swout: &'a [u8; 4],
+    /// The sACN priority (0-200) used when the node converts Art-Net to sACN. Defaults to 100, the sACN default.
+    ///
+    /// Note: Values above 200 are invalid in sACN and are clamped to 200 when serialized.
     pub acn_priority: u8,
     pub sw_macro: u8,
     pub sw_remote: u8,
@@ -60,11 +121,258 @@ pub struct PollReply<'a> {
     pub mac_address: &'a [u8; 6],
     pub bind_ip_address: &'a [u8; 4],
     pub bind_index: u8,
+    /// Defaults to advertising 15-bit Port-Address support (bit 3), as every Art-Net 4 node supports it.
     pub status2: u8,
     pub good_output_b: &'a [u8; 4],
     pub status3: u8,
     /// RDMnet & LLRP Default Responder UID
     pub default_responder_uid: &'a [u8; 6],
+    /// Vendor specific data written into the start of the 15 byte filler, the remainder is zero padded.
+    ///
+    /// When parsing, trailing zeros are stripped from the filler so a reply without extensions has no extra data.
+    pub extra: &'a [u8],
+}
+
+/// The type of equipment sending an ArtPollReply, as reported by its `style` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Style {
+    /// A DMX to / from Art-Net device
+    Node = 0x00,
+    /// A lighting console
+    Controller = 0x01,
+    /// A Media Server
+    Media = 0x02,
+    /// A network routing device
+    Route = 0x03,
+    /// A backup device
+    Backup = 0x04,
+    /// A configuration or diagnostic tool
+    Config = 0x05,
+    /// A visualiser
+    Visual = 0x06,
+}
+
+impl Style {
+    pub fn from_u8(style: u8) -> Option<Style> {
+        let style = match style {
+            0x00 => Style::Node,
+            0x01 => Style::Controller,
+            0x02 => Style::Media,
+            0x03 => Style::Route,
+            0x04 => Style::Backup,
+            0x05 => Style::Config,
+            0x06 => Style::Visual,
+            _ => return None,
+        };
+
+        Some(style)
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl TryFrom<u8> for Style {
+    /// The unknown style code
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Style::from_u8(value).ok_or(value)
+    }
+}
+
+/// The vendor of the equipment sending an ArtPollReply, as reported by its `oem` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Oem {
+    /// OemUnknown (0x00FF): Used by products that do not have an OEM code registered with Artistic Licence
+    Unknown,
+    /// An OEM code registered with Artistic Licence
+    Registered(u16),
+}
+
+impl Oem {
+    /// The OEM code sent by products without a registered code
+    const UNKNOWN: u16 = 0x00FF;
+
+    pub fn from_u16(oem: u16) -> Oem {
+        match oem {
+            Oem::UNKNOWN => Oem::Unknown,
+            oem => Oem::Registered(oem),
+        }
+    }
+
+    pub fn as_u16(&self) -> u16 {
+        match *self {
+            Oem::Unknown => Oem::UNKNOWN,
+            Oem::Registered(oem) => oem,
+        }
+    }
+}
+
+/// Every `oem` word decodes to an Oem (unregistered codes are kept as `Registered`)
+impl From<u16> for Oem {
+    fn from(oem: u16) -> Self {
+        Oem::from_u16(oem)
+    }
+}
+
+/// The state of a node's front panel indicators, reported in bits 7-6 of `status1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndicatorState {
+    Unknown,
+    /// Indicators in Locate / Identify Mode
+    Locate,
+    /// Indicators in Mute Mode
+    Mute,
+    /// Indicators in Normal Mode
+    Normal,
+}
+
+impl IndicatorState {
+    /// Decodes bits 7-6 of the `status1` field
+    pub fn from_status1(status1: u8) -> IndicatorState {
+        match status1 >> 6 {
+            0b00 => IndicatorState::Unknown,
+            0b01 => IndicatorState::Locate,
+            0b10 => IndicatorState::Mute,
+            _ => IndicatorState::Normal,
+        }
+    }
+
+    /// Whether a node's indicator LED should be lit at `tick` milliseconds (from any monotonic clock).
+    ///
+    /// Locate rapidly flashes the LED (4 times a second), Mute switches it off and Normal (or Unknown) leaves it on.
+    pub fn led_state(&self, tick: u32) -> bool {
+        /// Milliseconds the LED stays on (and then off) for when locating
+        const LOCATE_BLINK_MS: u32 = 125;
+
+        match self {
+            IndicatorState::Locate => (tick / LOCATE_BLINK_MS) & 1 == 0,
+            IndicatorState::Mute => false,
+            IndicatorState::Normal | IndicatorState::Unknown => true,
+        }
+    }
+
+    /// The indicator state encoded into bits 7-6 of a `status1` field
+    pub fn as_status1_bits(&self) -> u8 {
+        match self {
+            IndicatorState::Unknown => 0b0000_0000,
+            IndicatorState::Locate => 0b0100_0000,
+            IndicatorState::Mute => 0b1000_0000,
+            IndicatorState::Normal => 0b1100_0000,
+        }
+    }
+}
+
+/// Every `status1` byte decodes to an indicator state, so unlike the other enums this conversion is infallible.
+impl From<u8> for IndicatorState {
+    fn from(status1: u8) -> Self {
+        IndicatorState::from_status1(status1)
+    }
+}
+
+/// How a node refreshes an output port, reported in bit 6 of the port's `good_output_b`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStyle {
+    /// The output is refreshed continuously, even when no new data is received
+    Continuous,
+    /// Only changed channels are output (the DMX frame is sent when new data is received)
+    Delta,
+}
+
+impl OutputStyle {
+    /// Decodes bit 6 of a port's `good_output_b`
+    pub fn from_good_output_b(good_output_b: u8) -> OutputStyle {
+        if good_output_b & 0b0100_0000 != 0 {
+            OutputStyle::Continuous
+        } else {
+            OutputStyle::Delta
+        }
+    }
+
+    /// The output style encoded into bit 6 of a `good_output_b` byte
+    pub fn as_good_output_b_bits(&self) -> u8 {
+        match self {
+            OutputStyle::Continuous => 0b0100_0000,
+            OutputStyle::Delta => 0b0000_0000,
+        }
+    }
+}
+
+/// The status of a single output port, decoded from its `good_output_a` and `good_output_b` bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GoodOutput {
+    pub good_output_a: u8,
+    pub good_output_b: u8,
+}
+
+impl GoodOutput {
+    /// True if ArtDmx or ArtNzs data is being output as DMX512 on the port (bit 7 of `good_output_a`)
+    pub fn is_outputting(&self) -> bool {
+        self.good_output_a & 0b1000_0000 != 0
+    }
+
+    /// True if the output is merging Art-Net data from multiple sources (bit 3 of `good_output_a`)
+    pub fn is_merging(&self) -> bool {
+        self.good_output_a & 0b0000_1000 != 0
+    }
+
+    /// True if a DMX output short was detected on power up (bit 2 of `good_output_a`)
+    pub fn short_detected(&self) -> bool {
+        self.good_output_a & 0b0000_0100 != 0
+    }
+
+    /// True if the merge mode is LTP, otherwise it is HTP (bit 1 of `good_output_a`)
+    pub fn is_merge_ltp(&self) -> bool {
+        self.good_output_a & 0b0000_0010 != 0
+    }
+
+    /// True if the port outputs sACN, otherwise it outputs Art-Net (bit 0 of `good_output_a`)
+    pub fn is_sacn(&self) -> bool {
+        self.good_output_a & 0b0000_0001 != 0
+    }
+
+    /// True if RDM is disabled on the port (bit 7 of `good_output_b`)
+    pub fn is_rdm_disabled(&self) -> bool {
+        self.good_output_b & 0b1000_0000 != 0
+    }
+
+    /// Decodes bit 6 of `good_output_b`
+    pub fn output_style(&self) -> OutputStyle {
+        OutputStyle::from_good_output_b(self.good_output_b)
+    }
+}
+
+/// A node's firmware version, displayed as `major.minor` (the high byte then the low byte of the `firmware_version`
+/// field), eg. 0x0104 displays as "1.4".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FirmwareVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl FirmwareVersion {
+    pub fn from_major_minor(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    pub fn from_u16(firmware_version: u16) -> Self {
+        let [major, minor] = firmware_version.to_be_bytes();
+        Self { major, minor }
+    }
+
+    /// The u16 sent in the `firmware_version` field
+    pub fn as_u16(&self) -> u16 {
+        u16::from_be_bytes([self.major, self.minor])
+    }
+}
+
+impl fmt::Display for FirmwareVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
 }
 
 impl<'a> Default for PollReply<'a> {
@@ -88,27 +396,300 @@ impl<'a> Default for PollReply<'a> {
             good_output_a: crate::DEFAULT_4_BYTES,
             swin: crate::DEFAULT_4_BYTES,
             swout: crate::DEFAULT_4_BYTES,
-            acn_priority: Default::default(),
+            acn_priority: DEFAULT_ACN_PRIORITY,
             sw_macro: Default::default(),
             sw_remote: Default::default(),
             style: Default::default(),
             mac_address: crate::DEFAULT_6_BYTES,
             bind_ip_address: crate::DEFAULT_4_BYTES,
             bind_index: Default::default(),
-            status2: Default::default(),
+            status2: STATUS2_15_BIT_PORT_ADDRESS,
             good_output_b: crate::DEFAULT_4_BYTES,
             status3: Default::default(),
             default_responder_uid: crate::DEFAULT_6_BYTES,
+            extra: &[],
         }
     }
 }
 
 impl<'a> PollReply<'a> {
-    /// Serializes the PollReply into the provided buffer.
+    /// Returns the (MAC address, bind IP address) pair identifying the physical device that sent this reply.
     ///
-    /// Note: short name, long name and report will be truncated to 18, 64, and 64 bytes respectively
-    pub fn serialize(&self, mut buf: &mut [u8]) -> usize {
-        let initial_buf_len = buf.len();
+    /// Nodes with more than 4 ports send one reply per bound port so controllers should group
+    /// replies by this key to deduplicate discovery results.
+    pub fn device_key(&self) -> ([u8; 6], [u8; 4]) {
+        (*self.mac_address, *self.bind_ip_address)
+    }
+
+    /// The `firmware_version` field in its displayable `major.minor` form, eg. "1.4"
+    pub fn firmware_version_display(&self) -> FirmwareVersion {
+        FirmwareVersion::from_u16(self.firmware_version)
+    }
+
+    /// True if bit 3 of `status2` is set, ie. the node supports 15-bit Port-Addresses (Art-Net 3 or 4). Otherwise
+    /// the node is an Art-Net II node and the `net_switch` is ignored.
+    pub fn supports_15_bit_port_address(&self) -> bool {
+        self.status2 & STATUS2_15_BIT_PORT_ADDRESS != 0
+    }
+
+    /// Iterates over the (port index, Port-Address) of each input port, ie. those with the input bit (bit 6) of their
+    /// `port_types` set. The Net is 0 unless the node supports 15-bit Port-Addresses.
+    pub fn input_port_addresses(&self) -> impl Iterator<Item = (usize, PortAddress)> + 'a {
+        self.port_addresses(0b0100_0000, self.swin)
+    }
+
+    /// Iterates over the (port index, Port-Address) of each output port, ie. those with the output bit (bit 7) of
+    /// their `port_types` set. The Net is 0 unless the node supports 15-bit Port-Addresses.
+    pub fn output_port_addresses(&self) -> impl Iterator<Item = (usize, PortAddress)> + 'a {
+        self.port_addresses(0b1000_0000, self.swout)
+    }
+
+    /// The output style of the port (0-3), decoded from its `good_output_b`.
+    ///
+    /// Panics if the port is greater than 3.
+    pub fn output_style(&self, port: usize) -> OutputStyle {
+        OutputStyle::from_good_output_b(self.good_output_b[port])
+    }
+
+    /// Iterates over the Port-Address and status of each output port (see `output_port_addresses`), eg. for building
+    /// a controller's map of which nodes output each universe.
+    pub fn advertised_outputs(&self) -> impl Iterator<Item = (PortAddress, GoodOutput)> + 'a {
+        let (good_output_a, good_output_b) = (self.good_output_a, self.good_output_b);
+
+        self.output_port_addresses()
+            .map(move |(port, port_address)| {
+                (
+                    port_address,
+                    GoodOutput {
+                        good_output_a: good_output_a[port],
+                        good_output_b: good_output_b[port],
+                    },
+                )
+            })
+    }
+
+    /// Sets the `net_switch`, `sub_switch`, `num_ports`, per-port Universe nibbles and the 15-bit Port-Address
+    /// support bit of `status2` from the Port-Address of each of the node's ports. The nibbles are written into `sw`
+    /// which is used for both `swin` and `swout`, the `port_types` decide which direction each port has.
+    ///
+    /// A single ArtPollReply can only describe ports that share a Net and SubNet (and at most 4 ports), otherwise
+    /// the node must send a separate reply page (see `bind_index`) for the remaining ports.
+    pub fn set_ports(
+        &mut self,
+        addrs: &[PortAddress],
+        sw: &'a mut [u8; 4],
+    ) -> Result<(), MixedNetError> {
+        let first = addrs.first().copied().unwrap_or_default();
+
+        if let Some(port) = addrs
+            .iter()
+            .position(|addr| addr.net != first.net || addr.sub_net != first.sub_net)
+        {
+            return Err(MixedNetError { port });
+        }
+        if addrs.len() > MAX_PORTS as usize {
+            return Err(MixedNetError {
+                port: MAX_PORTS as usize,
+            });
+        }
+
+        *sw = [0; 4];
+        for (nibble, addr) in sw.iter_mut().zip(addrs) {
+            *nibble = addr.universe & 0x0F;
+        }
+
+        let sw: &'a [u8; 4] = sw;
+        // The Net is ignored unless the reply advertises 15-bit Port-Address support
+        self.status2 |= STATUS2_15_BIT_PORT_ADDRESS;
+        self.net_switch = first.net & 0x7F;
+        self.sub_switch = first.sub_net & 0x0F;
+        self.num_ports = addrs.len() as u16;
+        self.swin = sw;
+        self.swout = sw;
+
+        Ok(())
+    }
+
+    fn port_addresses(
+        &self,
+        port_type_bit: u8,
+        sw: &'a [u8; 4],
+    ) -> impl Iterator<Item = (usize, PortAddress)> + 'a {
+        // Art-Net II nodes only support 8-bit Port-Addresses so their Net is always 0
+        let net_switch = if self.supports_15_bit_port_address() {
+            self.net_switch
+        } else {
+            0
+        };
+        let (sub_switch, port_types) = (self.sub_switch, self.port_types);
+        let num_ports = (self.num_ports as usize).min(MAX_PORTS as usize);
+
+        (0..num_ports)
+            .filter(move |&port| port_types[port] & port_type_bit != 0)
+            .map(move |port| {
+                (
+                    port,
+                    port_address_from_reply(net_switch, sub_switch, sw[port]),
+                )
+            })
+    }
+
+    /// Decodes the `node_report`, returning None if it is not in the spec's "#xxxx [yyyy] zzzzz..." format.
+    pub fn decode_node_report(&self) -> Option<NodeReport<'a>> {
+        NodeReport::parse(self.node_report)
+    }
+
+    /// Formats the status code, counter and text into `buf` as a node report (see `NodeReport`) and points the
+    /// `node_report` at it.
+    pub fn set_node_report(
+        &mut self,
+        code: NodeReportCode,
+        counter: u16,
+        text: &str,
+        buf: &'a mut [u8; 64],
+    ) {
+        let report = NodeReport {
+            code: code.as_u16(),
+            counter,
+            text,
+        };
+
+        self.node_report = report.format(buf);
+    }
+
+    /// Decodes the `style` field. Returns None for styles not defined by the spec.
+    pub fn device_style(&self) -> Option<Style> {
+        Style::from_u8(self.style)
+    }
+
+    /// Sets the `style` field from a Style.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style.as_u8();
+        self
+    }
+
+    /// Decodes the indicator state from the `status1` field
+    pub fn indicator_state(&self) -> IndicatorState {
+        IndicatorState::from_status1(self.status1)
+    }
+
+    /// Decodes the `oem` field.
+    pub fn oem_code(&self) -> Oem {
+        Oem::from_u16(self.oem)
+    }
+
+    /// Sets the `oem` field from an Oem.
+    pub fn with_oem(mut self, oem: Oem) -> Self {
+        self.oem = oem.as_u16();
+        self
+    }
+
+    /// Sets the indicator state bits of the `status1` field, leaving the other bits unchanged.
+    pub fn with_indicator_state(mut self, indicator_state: IndicatorState) -> Self {
+        self.status1 = (self.status1 & 0b0011_1111) | indicator_state.as_status1_bits();
+        self
+    }
+
+    /// True if the reply was sent by a DMX to / from Art-Net node
+    pub fn is_node(&self) -> bool {
+        self.device_style() == Some(Style::Node)
+    }
+
+    /// True if the reply was sent by a lighting console
+    pub fn is_controller(&self) -> bool {
+        self.device_style() == Some(Style::Controller)
+    }
+
+    /// True if the reply was sent by a media server
+    pub fn is_media_server(&self) -> bool {
+        self.device_style() == Some(Style::Media)
+    }
+
+    /// Compares the replies ignoring the fields that change while a node is running: the `node_report` (which
+    /// contains a counter) and the indicator state bits of `status1` (which blink when locating a node).
+    ///
+    /// Useful for detecting when a node's configuration has actually changed.
+    pub fn eq_ignoring_volatile(&self, other: &PollReply) -> bool {
+        self.ip_address == other.ip_address
+            && self.port == other.port
+            && self.firmware_version == other.firmware_version
+            && self.net_switch == other.net_switch
+            && self.sub_switch == other.sub_switch
+            && self.oem == other.oem
+            && self.ubea_version == other.ubea_version
+            && self.status1 & 0b0011_1111 == other.status1 & 0b0011_1111
+            && self.esta_manufacturer_code == other.esta_manufacturer_code
+            && self.short_name == other.short_name
+            && self.long_name == other.long_name
+            && self.num_ports == other.num_ports
+            && self.port_types == other.port_types
+            && self.good_input == other.good_input
+            && self.good_output_a == other.good_output_a
+            && self.swin == other.swin
+            && self.swout == other.swout
+            && self.acn_priority == other.acn_priority
+            && self.sw_macro == other.sw_macro
+            && self.sw_remote == other.sw_remote
+            && self.style == other.style
+            && self.mac_address == other.mac_address
+            && self.bind_ip_address == other.bind_ip_address
+            && self.bind_index == other.bind_index
+            && self.status2 == other.status2
+            && self.good_output_b == other.good_output_b
+            && self.status3 == other.status3
+            && self.default_responder_uid == other.default_responder_uid
+            && self.extra == other.extra
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        POLL_REPLY_LEN
+    }
+
+    /// Serializes the PollReply into the provided buffer, returning an error instead of panicking if the buffer is too
+    /// small, `extra` does not fit in the filler or the `net_switch` / `sub_switch` do not fit in 7 / 4 bits.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        if self.net_switch > 0x7F {
+            return Err(SerializeError::OutOfRange {
+                field: "net_switch",
+                max: 0x7F,
+            });
+        }
+
+        if self.sub_switch > 0x0F {
+            return Err(SerializeError::OutOfRange {
+                field: "sub_switch",
+                max: 0x0F,
+            });
+        }
+
+        if self.extra.len() > FILLER_LEN {
+            return Err(SerializeError::ExtraTooLong { max: FILLER_LEN });
+        }
+
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the PollReply into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    ///
+    /// Note: short name, long name and report will be truncated to 18, 64, and 64 bytes respectively and extra to 15 bytes
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        self.put(sink, false)
+    }
+
+    /// Serializes the shorter Art-Net 3 form of the PollReply, ending after `status2`, returning the number of
+    /// bytes written.
+    ///
+    /// Some older controllers mis-parse the longer Art-Net 4 reply so nodes may send this instead when replying to
+    /// them.
+    pub fn serialize_legacy<S: ByteSink>(&self, sink: S) -> usize {
+        self.put(sink, true)
+    }
+
+    fn put<S: ByteSink>(&self, sink: S, legacy: bool) -> usize {
+        let mut buf = Counter::new(sink);
 
         buf.put_slice(crate::ID);
         buf.put_u16_le(OP_POLL_REPLY);
@@ -122,9 +703,9 @@ impl<'a> PollReply<'a> {
         buf.put_u8(self.status1);
         put_esta_manufacturer_code(&mut buf, &self.esta_manufacturer_code);
 
-        crate::put_padded_str::<18, _>(&mut buf, &self.short_name);
-        crate::put_padded_str::<64, _>(&mut buf, &self.long_name);
-        crate::put_padded_str::<64, _>(&mut buf, &self.node_report);
+        crate::put_padded_str::<18, _>(&mut buf, self.short_name);
+        crate::put_padded_str::<64, _>(&mut buf, self.long_name);
+        crate::put_padded_str::<64, _>(&mut buf, self.node_report);
 
         buf.put_u16(self.num_ports);
         buf.put_slice(self.port_types);
@@ -132,7 +713,7 @@ impl<'a> PollReply<'a> {
         buf.put_slice(self.good_output_a);
         buf.put_slice(self.swin);
         buf.put_slice(self.swout);
-        buf.put_u8(self.acn_priority);
+        buf.put_u8(self.acn_priority.min(MAX_ACN_PRIORITY));
         buf.put_u8(self.sw_macro);
         buf.put_u8(self.sw_remote);
         // Spare
@@ -142,16 +723,214 @@ impl<'a> PollReply<'a> {
         buf.put_slice(self.bind_ip_address);
         buf.put_u8(self.bind_index);
         buf.put_u8(self.status2);
+
+        if legacy {
+            return buf.written();
+        }
+
         buf.put_slice(self.good_output_b);
         buf.put_u8(self.status3);
         buf.put_slice(self.default_responder_uid);
         // Filler
-        buf.put_slice(&[0u8; 15]);
+        let extra = &self.extra[..self.extra.len().min(FILLER_LEN)];
+        buf.put_slice(extra);
+        buf.put_slice(&[0u8; FILLER_LEN][extra.len()..]);
 
-        return initial_buf_len - buf.len();
+        buf.written()
     }
 }
 
-// TODO: Poll Reply Parser
-// pub fn from_str<'a>(s: &'a [u8]) -> Result<PollReply<'a>, crate::Error<'a>> {
-// }
+/// Parses the body of an ArtPollReply. Note: ArtPollReply is the only packet that does not include a protocol version.
+///
+/// Older (Art-Net II and 3) nodes send shorter replies without the trailing fields added in later
+/// versions of the spec. Missing fields are filled in with their defaults rather than returning an error.
+pub(crate) fn parse_poll_reply<'a>(s: &'a [u8]) -> Result<PollReply<'a>, crate::Error<'a>> {
+    let (s, ip_address) = parse_array::<4>(s)?;
+    let (s, port) = crate::read_udp_port(s)?;
+    let (s, firmware_version) = be_u16(s)?;
+    let (s, net_switch) = number::u8(s)?;
+    let (s, sub_switch) = number::u8(s)?;
+    let (s, oem) = be_u16(s)?;
+    let (s, ubea_version) = number::u8(s)?;
+    let (s, status1) = number::u8(s)?;
+    let (s, esta_manufacturer_code) = parse_esta_manufacturer_code(s)?;
+
+    let (s, short_name) = parse_padded_str::<18>(s)?;
+    let (s, long_name) = parse_padded_str::<64>(s)?;
+    let (s, node_report) = parse_padded_str::<64>(s)?;
+
+    let (s, num_ports) = verify(be_u16, |num_ports| *num_ports <= MAX_PORTS)(s)?;
+    let (s, port_types) = parse_array::<4>(s)?;
+    let (s, good_input) = parse_array::<4>(s)?;
+    let (s, good_output_a) = parse_array::<4>(s)?;
+    let (s, swin) = parse_array::<4>(s)?;
+    let (s, swout) = parse_array::<4>(s)?;
+    let (s, acn_priority) = number::u8(s)?;
+    let (s, sw_macro) = number::u8(s)?;
+    let (s, sw_remote) = number::u8(s)?;
+    // Spare
+    let (s, _) = parse_array::<3>(s)?;
+    let (s, style) = number::u8(s)?;
+    let (s, mac_address) = parse_array::<6>(s)?;
+
+    // Added in Art-Net 3
+    let (s, art_net_3_fields) = opt(tuple((parse_array::<4>, number::u8, number::u8)))(s)?;
+    let (bind_ip_address, bind_index, status2) =
+        art_net_3_fields.unwrap_or((crate::DEFAULT_4_BYTES, 0, 0));
+
+    // Added in Art-Net 4
+    let (s, art_net_4_fields) = opt(tuple((parse_array::<4>, number::u8, parse_array::<6>)))(s)?;
+    let (good_output_b, status3, default_responder_uid) =
+        art_net_4_fields.unwrap_or((crate::DEFAULT_4_BYTES, 0, crate::DEFAULT_6_BYTES));
+
+    // Filler
+    let filler = &s[..s.len().min(FILLER_LEN)];
+    let extra_len = filler.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+    let extra = &filler[..extra_len];
+
+    Ok(PollReply {
+        ip_address,
+        port,
+        firmware_version,
+        net_switch,
+        sub_switch,
+        oem,
+        ubea_version,
+        status1,
+        esta_manufacturer_code,
+        short_name,
+        long_name,
+        node_report,
+        num_ports,
+        port_types,
+        good_input,
+        good_output_a,
+        swin,
+        swout,
+        acn_priority,
+        sw_macro,
+        sw_remote,
+        style,
+        mac_address,
+        bind_ip_address,
+        bind_index,
+        status2,
+        good_output_b,
+        status3,
+        default_responder_uid,
+        extra,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_serialize_reports_the_needed_length() {
+        let reply = PollReply {
+            short_name: "Short Buffer Node",
+            ..Default::default()
+        };
+
+        let mut buf = [0u8; 512];
+        for len in [0, 10, POLL_REPLY_LEN - 1] {
+            match reply.try_serialize(&mut buf[..len]) {
+                Err(SerializeError::BufferTooSmall { needed }) => {
+                    assert_eq!(needed, POLL_REPLY_LEN)
+                }
+                result => panic!("Expected BufferTooSmall but received: {:?}", result),
+            };
+        }
+
+        assert_eq!(reply.try_serialize(&mut buf[..]).unwrap(), POLL_REPLY_LEN);
+        assert_eq!(reply.serialize(&mut buf[..]), POLL_REPLY_LEN);
+    }
+
+    #[test]
+    fn fields_are_serialized_at_their_documented_offsets() {
+        let reply = PollReply {
+            ip_address: &[10, 11, 12, 13],
+            port: 0x1936,
+            firmware_version: 0x0102,
+            net_switch: 0x03,
+            sub_switch: 0x04,
+            oem: 0x0506,
+            ubea_version: 0x07,
+            status1: 0x08,
+            esta_manufacturer_code: ('\u{09}', '\u{0a}'),
+            short_name: "Short",
+            long_name: "Long",
+            node_report: "Report",
+            num_ports: 0x0B0C,
+            port_types: &[0x11, 0x12, 0x13, 0x14],
+            good_input: &[0x21, 0x22, 0x23, 0x24],
+            good_output_a: &[0x31, 0x32, 0x33, 0x34],
+            swin: &[0x41, 0x42, 0x43, 0x44],
+            swout: &[0x51, 0x52, 0x53, 0x54],
+            acn_priority: 0x61,
+            sw_macro: 0x62,
+            sw_remote: 0x63,
+            style: 0x64,
+            mac_address: &[0x71, 0x72, 0x73, 0x74, 0x75, 0x76],
+            bind_ip_address: &[0x81, 0x82, 0x83, 0x84],
+            bind_index: 0x85,
+            status2: 0x86,
+            good_output_b: &[0x91, 0x92, 0x93, 0x94],
+            status3: 0x95,
+            default_responder_uid: &[0xA1, 0xA2, 0xA3, 0xA4, 0xA5, 0xA6],
+            extra: &[0xB1, 0xB2],
+        };
+
+        let mut buf = [0xFF; 512];
+        assert_eq!(reply.serialize(&mut buf[..]), 239);
+
+        let field = |offset: usize, len: usize| &buf[offset..offset + len];
+
+        assert_eq!(field(0, 8), b"Art-Net\0");
+        // OpCode (Lo, Hi)
+        assert_eq!(field(8, 2), &[0x00, 0x21]);
+        assert_eq!(field(10, 4), &[10, 11, 12, 13]);
+        // Port (Lo, Hi)
+        assert_eq!(field(14, 2), &[0x36, 0x19]);
+        // VersInfo (Hi, Lo)
+        assert_eq!(field(16, 2), &[0x01, 0x02]);
+        assert_eq!(field(18, 1), &[0x03]);
+        assert_eq!(field(19, 1), &[0x04]);
+        // Oem (Hi, Lo)
+        assert_eq!(field(20, 2), &[0x05, 0x06]);
+        assert_eq!(field(22, 1), &[0x07]);
+        assert_eq!(field(23, 1), &[0x08]);
+        // EstaMan (Lo, Hi)
+        assert_eq!(field(24, 2), &[0x09, 0x0A]);
+        assert_eq!(field(26, 18), b"Short\0\0\0\0\0\0\0\0\0\0\0\0\0");
+        assert_eq!(field(44, 5), b"Long\0");
+        assert!(field(49, 59).iter().all(|byte| *byte == 0));
+        assert_eq!(field(108, 7), b"Report\0");
+        assert!(field(115, 57).iter().all(|byte| *byte == 0));
+        // NumPorts (Hi, Lo)
+        assert_eq!(field(172, 2), &[0x0B, 0x0C]);
+        assert_eq!(field(174, 4), &[0x11, 0x12, 0x13, 0x14]);
+        assert_eq!(field(178, 4), &[0x21, 0x22, 0x23, 0x24]);
+        assert_eq!(field(182, 4), &[0x31, 0x32, 0x33, 0x34]);
+        assert_eq!(field(186, 4), &[0x41, 0x42, 0x43, 0x44]);
+        assert_eq!(field(190, 4), &[0x51, 0x52, 0x53, 0x54]);
+        assert_eq!(field(194, 1), &[0x61]);
+        assert_eq!(field(195, 1), &[0x62]);
+        assert_eq!(field(196, 1), &[0x63]);
+        // Spare
+        assert_eq!(field(197, 3), &[0, 0, 0]);
+        assert_eq!(field(200, 1), &[0x64]);
+        assert_eq!(field(201, 6), &[0x71, 0x72, 0x73, 0x74, 0x75, 0x76]);
+        assert_eq!(field(207, 4), &[0x81, 0x82, 0x83, 0x84]);
+        assert_eq!(field(211, 1), &[0x85]);
+        assert_eq!(field(212, 1), &[0x86]);
+        assert_eq!(field(213, 4), &[0x91, 0x92, 0x93, 0x94]);
+        assert_eq!(field(217, 1), &[0x95]);
+        assert_eq!(field(218, 6), &[0xA1, 0xA2, 0xA3, 0xA4, 0xA5, 0xA6]);
+        // Filler
+        assert_eq!(field(224, 2), &[0xB1, 0xB2]);
+        assert!(field(226, 13).iter().all(|byte| *byte == 0));
+        assert_eq!(buf[239], 0xFF);
+    }
+}
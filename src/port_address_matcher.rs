@@ -0,0 +1,37 @@
+use crate::PortAddress;
+
+/// Matches Port-Addresses with optional wildcard components, eg. for routing "any universe on net 0".
+///
+/// Each component that is None matches any value.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PortAddressMatcher {
+    pub net: Option<u8>,
+    pub sub_net: Option<u8>,
+    pub universe: Option<u8>,
+}
+
+impl PortAddressMatcher {
+    /// A matcher for exactly the given Port-Address
+    pub fn exact(port_address: &PortAddress) -> Self {
+        Self {
+            net: Some(port_address.net),
+            sub_net: Some(port_address.sub_net),
+            universe: Some(port_address.universe),
+        }
+    }
+
+    /// Returns true if every component that is not a wildcard equals the Port-Address's component
+    pub fn matches(&self, port_address: &PortAddress) -> bool {
+        component_matches(self.net, port_address.net)
+            && component_matches(self.sub_net, port_address.sub_net)
+            && component_matches(self.universe, port_address.universe)
+    }
+}
+
+// A wildcard (None) matches any value
+fn component_matches(component: Option<u8>, value: u8) -> bool {
+    match component {
+        Some(component) => component == value,
+        None => true,
+    }
+}
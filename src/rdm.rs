@@ -0,0 +1,91 @@
+use nom::number::complete as number;
+
+use crate::{parse_array, put_header, sink::Counter, ByteSink, PortAddress, SerializeError};
+
+pub(crate) const OP_RDM: u16 = 0x8300;
+
+/// Length of an ArtRdm packet excluding the RDM packet
+pub(crate) const RDM_HEADER_LEN: usize = 24;
+
+/// The RDM version sent by devices implementing RDM Standard V1.0
+const RDM_VERSION_1_0: u8 = 0x01;
+
+/// ArtRdm tunnels a single RDM message (eg. for discovery or configuration) between a controller and a node's
+/// DMX port.
+#[derive(Debug)]
+pub struct Rdm<'a> {
+    /// 0x00 for draft devices, 0x01 for devices implementing RDM Standard V1.0
+    pub rdm_version: u8,
+    /// The Port-Address of the DMX port the RDM message is for
+    pub port_address: PortAddress,
+    /// The action to perform, 0x00 (ArProcess) to process the RDM message
+    pub command: u8,
+    /// The RDM message excluding the DMX start code
+    pub data: &'a [u8],
+}
+
+pub(crate) fn parse_rdm<'a>(s: &'a [u8]) -> Result<Rdm<'a>, crate::Error<'a>> {
+    let (s, rdm_version) = number::u8(s)?;
+    // Filler and Spare
+    let (s, _) = parse_array::<8>(s)?;
+    let (s, net) = number::u8(s)?;
+    let (s, command) = number::u8(s)?;
+    let (data, address) = number::u8(s)?;
+
+    Ok(Rdm {
+        rdm_version,
+        port_address: PortAddress::from_net_subuni(net, address),
+        command,
+        data,
+    })
+}
+
+/// The length of the RDM message at the start of `s` (excluding the DMX start code), read from its message length
+/// slot. Returns None if `s` is too short to contain the message length.
+pub(crate) fn rdm_message_len(s: &[u8]) -> Option<usize> {
+    // The message length counts the start code but not the 2 byte checksum
+    s.get(1)
+        .map(|message_length| (*message_length as usize).saturating_sub(1) + 2)
+}
+
+impl<'a> Rdm<'a> {
+    /// Creates an ArtRdm that asks the node to process the RDM message (ArProcess).
+    pub fn new(port_address: PortAddress, data: &'a [u8]) -> Self {
+        Self {
+            rdm_version: RDM_VERSION_1_0,
+            port_address,
+            command: 0x00,
+            data,
+        }
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        RDM_HEADER_LEN + self.data.len()
+    }
+
+    /// Serializes the ArtRdm into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the ArtRdm into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+
+        let (net, address) = self.port_address.to_net_subuni();
+
+        put_header(&mut buf, OP_RDM);
+        buf.put_u8(self.rdm_version);
+        // Filler and Spare
+        buf.put_slice(&[0u8; 8]);
+        buf.put_u8(net);
+        buf.put_u8(self.command);
+        buf.put_u8(address);
+        buf.put_slice(self.data);
+
+        buf.written()
+    }
+}
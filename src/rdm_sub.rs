@@ -0,0 +1,83 @@
+use nom::{
+    bytes::complete::take,
+    number::complete::{self as number, be_u16},
+};
+
+use crate::parse_array;
+
+pub(crate) const OP_RDM_SUB: u16 = 0x8400;
+
+/// Length of an ArtRdmSub packet excluding the data
+pub(crate) const RDM_SUB_HEADER_LEN: usize = 32;
+
+/// RDM Get Response command class
+const GET_COMMAND_RESPONSE: u8 = 0x21;
+/// RDM Set command class
+const SET_COMMAND: u8 = 0x30;
+
+/// The length of the data following the header, only Set and Get Response packets contain data.
+pub(crate) fn data_len(command_class: u8, sub_count: u16) -> usize {
+    match command_class {
+        SET_COMMAND | GET_COMMAND_RESPONSE => sub_count as usize * 2,
+        _ => 0,
+    }
+}
+
+/// ArtRdmSub transfers compressed RDM data for a range of sub-devices.
+#[derive(Debug)]
+pub struct RdmSub<'a> {
+    pub rdm_version: u8,
+    /// The UID of the RDM device
+    pub uid: &'a [u8; 6],
+    /// The RDM command class (eg. 0x20 for Get)
+    pub command_class: u8,
+    /// The RDM parameter ID
+    pub parameter_id: u16,
+    /// The first sub-device
+    pub sub_device: u16,
+    /// The number of sub-devices
+    pub sub_count: u16,
+    /// Packed big endian u16 values, one per sub-device. Only Set and Get Response packets contain data.
+    pub data: &'a [u8],
+}
+
+pub(crate) fn parse_rdm_sub<'a>(s: &'a [u8]) -> Result<RdmSub<'a>, crate::Error<'a>> {
+    let (s, rdm_version) = number::u8(s)?;
+    let (s, _filler2) = number::u8(s)?;
+    let (s, uid) = parse_array::<6>(s)?;
+    let (s, _spare1) = number::u8(s)?;
+    let (s, command_class) = number::u8(s)?;
+    let (s, parameter_id) = be_u16(s)?;
+    let (s, sub_device) = be_u16(s)?;
+    let (s, sub_count) = be_u16(s)?;
+    let (s, _spare) = parse_array::<4>(s)?;
+
+    let (_s, data) = take(data_len(command_class, sub_count))(s)?;
+
+    Ok(RdmSub {
+        rdm_version,
+        uid,
+        command_class,
+        parameter_id,
+        sub_device,
+        sub_count,
+        data,
+    })
+}
+
+impl<'a> RdmSub<'a> {
+    /// Iterates over the (sub-device, value) pairs in the packet's data.
+    pub fn sub_device_data(&self) -> impl Iterator<Item = (u16, u16)> + 'a {
+        let sub_device = self.sub_device;
+
+        self.data
+            .chunks_exact(2)
+            .enumerate()
+            .map(move |(i, value)| {
+                (
+                    sub_device.wrapping_add(i as u16),
+                    u16::from_be_bytes([value[0], value[1]]),
+                )
+            })
+    }
+}
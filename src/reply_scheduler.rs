@@ -0,0 +1,40 @@
+/// Decides when a node should broadcast an unsolicited ArtPollReply, eg. as a keep-alive on networks where
+/// controllers do not poll regularly.
+///
+/// The scheduler is clock agnostic: ticks can be milliseconds, timer interrupts or any other monotonic counter as long
+/// as the interval is in the same unit. Counters that wrap around are supported.
+#[derive(Debug, Clone)]
+pub struct ReplyScheduler {
+    interval: u32,
+    last_reply: Option<u32>,
+}
+
+impl ReplyScheduler {
+    /// Creates a scheduler that fires every `interval` ticks, starting with the first call to `should_reply`.
+    pub fn new(interval: u32) -> Self {
+        Self {
+            interval,
+            last_reply: None,
+        }
+    }
+
+    /// Returns true if an unsolicited reply is due at the tick `now`, in which case the reply is recorded as sent.
+    pub fn should_reply(&mut self, now: u32) -> bool {
+        let due = match self.last_reply {
+            Some(last_reply) => now.wrapping_sub(last_reply) >= self.interval,
+            None => true,
+        };
+
+        if due {
+            self.last_reply = Some(now);
+        }
+
+        due
+    }
+
+    /// Records a reply sent at the tick `now` (eg. in response to an ArtPoll), postponing the next unsolicited reply
+    /// by a full interval.
+    pub fn replied(&mut self, now: u32) {
+        self.last_reply = Some(now);
+    }
+}
@@ -0,0 +1,45 @@
+/// Detects dropped ArtDmx packets using their `sequence` field, eg. for monitoring link quality.
+///
+/// Sequence numbers are only comparable within a single Port-Address so use one monitor per Port-Address.
+#[derive(Debug, Default, Clone)]
+pub struct SequenceMonitor {
+    last_sequence: Option<u8>,
+}
+
+impl SequenceMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the sequence number of a received packet, returning the number of packets dropped since the previous
+    /// packet.
+    ///
+    /// Sequence numbers are incremented from 0x01 to 0xFF and then wrap back around to 0x01. A sequence of 0x00 means
+    /// the sender has disabled sequencing so no gaps are reported. Packets arriving late (ie. out of order) or
+    /// duplicated are not counted as gaps.
+    pub fn observe(&mut self, sequence: u8) -> u8 {
+        if sequence == 0 {
+            self.last_sequence = None;
+            return 0;
+        }
+
+        let last_sequence = match self.last_sequence {
+            Some(last_sequence) => last_sequence,
+            None => {
+                self.last_sequence = Some(sequence);
+                return 0;
+            }
+        };
+
+        // The distance travelled around the 255 value (0x01 to 0xFF) sequence
+        let distance = (sequence as u16 + 255 - last_sequence as u16) % 255;
+
+        // Treat packets more than half way around the sequence behind the last one as late rather than a gap
+        if distance == 0 || distance > 127 {
+            return 0;
+        }
+
+        self.last_sequence = Some(sequence);
+        (distance - 1) as u8
+    }
+}
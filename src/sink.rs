@@ -0,0 +1,185 @@
+use core::mem::MaybeUninit;
+
+/// A destination for serialized Art-Net packets.
+///
+/// Implemented for `&mut [u8]` and `&mut [MaybeUninit<u8>]` (which are advanced past the written bytes, like
+/// `BufMut`) and, with the `heapless` feature enabled, for `heapless::Vec<u8, N>`. Embedded transports that expose a
+/// byte sink rather than a slice can implement `put_slice` to serialize directly into it.
+///
+/// Like `BufMut`, writing past the end of a fixed capacity sink panics.
+pub trait ByteSink {
+    /// Appends the bytes to the sink.
+    fn put_slice(&mut self, src: &[u8]);
+
+    fn put_u8(&mut self, n: u8) {
+        self.put_slice(&[n]);
+    }
+
+    /// Appends a big endian u16
+    fn put_u16(&mut self, n: u16) {
+        self.put_slice(&n.to_be_bytes());
+    }
+
+    /// Appends a little endian u16
+    fn put_u16_le(&mut self, n: u16) {
+        self.put_slice(&n.to_le_bytes());
+    }
+}
+
+impl ByteSink for &mut [u8] {
+    fn put_slice(&mut self, src: &[u8]) {
+        let (written, rest) = core::mem::take(self).split_at_mut(src.len());
+        written.copy_from_slice(src);
+        *self = rest;
+    }
+}
+
+/// Allows serializing into a buffer that has not been zeroed first. The bytes up to the returned length are
+/// initialized.
+impl ByteSink for &mut [MaybeUninit<u8>] {
+    fn put_slice(&mut self, src: &[u8]) {
+        let (written, rest) = core::mem::take(self).split_at_mut(src.len());
+        for (dst, byte) in written.iter_mut().zip(src) {
+            dst.write(*byte);
+        }
+        *self = rest;
+    }
+}
+
+impl<S: ByteSink + ?Sized> ByteSink for &mut S {
+    fn put_slice(&mut self, src: &[u8]) {
+        (**self).put_slice(src);
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> ByteSink for heapless::Vec<u8, N> {
+    fn put_slice(&mut self, src: &[u8]) {
+        self.extend_from_slice(src)
+            .expect("heapless::Vec does not have enough capacity for the packet");
+    }
+}
+
+/// Wraps a sink to count the bytes written to it
+pub(crate) struct Counter<S> {
+    sink: S,
+    written: usize,
+}
+
+impl<S: ByteSink> Counter<S> {
+    pub(crate) fn new(sink: S) -> Self {
+        Self { sink, written: 0 }
+    }
+
+    pub(crate) fn written(&self) -> usize {
+        self.written
+    }
+}
+
+impl<S: ByteSink> ByteSink for Counter<S> {
+    fn put_slice(&mut self, src: &[u8]) {
+        self.sink.put_slice(src);
+        self.written += src.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{serialize_sync, Command, Dmx, Nzs, Poll, PollReply, PortAddress, Rdm};
+
+    /// A sink that records every byte written to it, like a transport's transmit FIFO
+    struct MockSink {
+        bytes: [u8; 1024],
+        len: usize,
+    }
+
+    impl MockSink {
+        fn new() -> Self {
+            Self {
+                bytes: [0; 1024],
+                len: 0,
+            }
+        }
+
+        fn written(&self) -> &[u8] {
+            &self.bytes[..self.len]
+        }
+    }
+
+    impl ByteSink for MockSink {
+        fn put_slice(&mut self, src: &[u8]) {
+            self.bytes[self.len..self.len + src.len()].copy_from_slice(src);
+            self.len += src.len();
+        }
+    }
+
+    #[test]
+    fn poll_reply_serializes_identically_to_a_slice_and_a_mock_sink() {
+        let reply = PollReply {
+            ip_address: &[192, 168, 0, 10],
+            port: crate::PORT,
+            short_name: "Mock Node",
+            long_name: "Tiny Artnet Mock Node",
+            num_ports: 1,
+            port_types: &[0b1000_0000, 0, 0, 0],
+            ..Default::default()
+        };
+
+        let mut buf = [0u8; 1024];
+        let slice_len = reply.serialize(&mut buf[..]);
+
+        let mut sink = MockSink::new();
+        let sink_len = reply.serialize(&mut sink);
+
+        assert_eq!(slice_len, reply.serialized_len());
+        assert_eq!(sink_len, slice_len);
+        assert_eq!(sink.written(), &buf[..slice_len]);
+    }
+
+    #[test]
+    fn serializers_count_exactly_the_bytes_they_write() {
+        let port_address = PortAddress::from_index(42).unwrap();
+        let data = [1, 2, 3];
+
+        let mut sink = MockSink::new();
+        let len = Poll::default().serialize(&mut sink);
+        assert_eq!(len, sink.len);
+
+        let mut sink = MockSink::new();
+        let len = Dmx::new(port_address, &data).serialize(&mut sink, true);
+        assert_eq!(len, sink.len);
+
+        let mut sink = MockSink::new();
+        let len = Nzs::new(0x17, port_address, &data).serialize(&mut sink);
+        assert_eq!(len, sink.len);
+
+        let mut sink = MockSink::new();
+        let len = Rdm::new(port_address, &data).serialize(&mut sink);
+        assert_eq!(len, sink.len);
+
+        let mut sink = MockSink::new();
+        let len =
+            Command::new(crate::ESTA_BROADCAST, b"SwoutText=Playback&").serialize(&mut sink, true);
+        assert_eq!(len, sink.len);
+
+        let mut sink = MockSink::new();
+        let len = serialize_sync(&mut sink);
+        assert_eq!(len, sink.len);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn poll_reply_serializes_into_a_heapless_vec() {
+        let reply = PollReply::default();
+
+        let mut buf = [0u8; 1024];
+        let slice_len = reply.serialize(&mut buf[..]);
+
+        let mut vec = heapless::Vec::<u8, 1024>::new();
+        let vec_len = reply.serialize(&mut vec);
+
+        assert_eq!(vec_len, slice_len);
+        assert_eq!(&vec[..], &buf[..slice_len]);
+    }
+}
@@ -0,0 +1,110 @@
+use nom::{combinator::map_opt, number::complete as number};
+
+use crate::{parse_array, put_header, sink::Counter, ByteSink, PortAddress, SerializeError};
+
+pub(crate) const OP_TOD_CONTROL: u16 = 0x8200;
+
+/// Length of a serialized ArtTodControl, including the header
+pub(crate) const TOD_CONTROL_LEN: usize = 24;
+
+/// The action requested of a node by an ArtTodControl.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum TodControlCommand {
+    /// AtcNone: No action.
+    None = 0x00,
+    /// AtcFlush: The node flushes its Table of Devices and instigates full discovery.
+    Flush = 0x01,
+    /// AtcEnd: The node ends incremental discovery.
+    End = 0x02,
+    /// AtcIncOn: The node enables incremental discovery.
+    IncrementalOn = 0x03,
+    /// AtcIncOff: The node disables incremental discovery.
+    IncrementalOff = 0x04,
+}
+
+impl TodControlCommand {
+    pub fn from_u8(command: u8) -> Option<TodControlCommand> {
+        let command = match command {
+            0x00 => TodControlCommand::None,
+            0x01 => TodControlCommand::Flush,
+            0x02 => TodControlCommand::End,
+            0x03 => TodControlCommand::IncrementalOn,
+            0x04 => TodControlCommand::IncrementalOff,
+            _ => return None,
+        };
+
+        Some(command)
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl TryFrom<u8> for TodControlCommand {
+    /// The unknown command
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        TodControlCommand::from_u8(value).ok_or(value)
+    }
+}
+
+/// ArtTodControl is used by a controller to send RDM discovery control messages to a node.
+#[derive(Debug)]
+pub struct TodControl {
+    /// The Port-Address of the DMX port the command applies to
+    pub port_address: PortAddress,
+    pub command: TodControlCommand,
+}
+
+pub(crate) fn parse_tod_control<'a>(s: &'a [u8]) -> Result<TodControl, crate::Error<'a>> {
+    // Filler and Spare
+    let (s, _) = parse_array::<9>(s)?;
+    let (s, net) = number::u8(s)?;
+    let (s, command) = map_opt(number::u8, TodControlCommand::from_u8)(s)?;
+    let (_s, address) = number::u8(s)?;
+
+    Ok(TodControl {
+        port_address: PortAddress::from_net_subuni(net, address),
+        command,
+    })
+}
+
+impl TodControl {
+    pub fn new(port_address: PortAddress, command: TodControlCommand) -> Self {
+        Self {
+            port_address,
+            command,
+        }
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        TOD_CONTROL_LEN
+    }
+
+    /// Serializes the ArtTodControl into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the ArtTodControl into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+
+        let (net, address) = self.port_address.to_net_subuni();
+
+        put_header(&mut buf, OP_TOD_CONTROL);
+        // Filler and Spare
+        buf.put_slice(&[0u8; 9]);
+        buf.put_u8(net);
+        buf.put_u8(self.command.as_u8());
+        buf.put_u8(address);
+
+        buf.written()
+    }
+}
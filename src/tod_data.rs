@@ -0,0 +1,109 @@
+use nom::{
+    bytes::complete::take,
+    number::complete::{self as number, be_u16},
+};
+
+use crate::{parse_array, put_header, sink::Counter, ByteSink, PortAddress, SerializeError};
+
+pub(crate) const OP_TOD_DATA: u16 = 0x8100;
+
+/// Length of an ArtTodData packet excluding the UIDs
+pub(crate) const TOD_DATA_HEADER_LEN: usize = 28;
+
+/// The RDM standard version sent by Art-Net 4 nodes (RDM STANDARD V1.0)
+const RDM_VERSION: u8 = 0x01;
+
+/// ArtTodData is sent by a node to report the Table of Devices (ie. the RDM UIDs discovered) on one of its DMX
+/// ports.
+///
+/// Large tables are split across several packets, see `uid_total` and `block_count`.
+#[derive(Debug)]
+pub struct TodData<'a> {
+    /// The physical port (1-4) of the node the table was discovered on
+    pub port: u8,
+    /// The bind index of the node (or port) sending the table
+    pub bind_index: u8,
+    /// The Port-Address of the port the table was discovered on
+    pub port_address: PortAddress,
+    /// True if the table is a TodNak response ie. the node's table of devices is not available
+    pub nak: bool,
+    /// The total number of UIDs in the node's table of devices
+    pub uid_total: u16,
+    /// The index of this packet when the table is split across multiple packets, starting at 0
+    pub block_count: u8,
+    /// The packed 6 byte UIDs in this packet. See `uids`.
+    pub uids: &'a [u8],
+}
+
+pub(crate) fn parse_tod_data<'a>(s: &'a [u8]) -> Result<TodData<'a>, crate::Error<'a>> {
+    let (s, _rdm_version) = number::u8(s)?;
+    let (s, port) = number::u8(s)?;
+    // Spare
+    let (s, _) = parse_array::<6>(s)?;
+    let (s, bind_index) = number::u8(s)?;
+    let (s, net) = number::u8(s)?;
+    let (s, command_response) = number::u8(s)?;
+    let (s, address) = number::u8(s)?;
+    let (s, uid_total) = be_u16(s)?;
+    let (s, block_count) = number::u8(s)?;
+    let (s, uid_count) = number::u8(s)?;
+    let (_s, uids) = take(uid_count as usize * 6)(s)?;
+
+    Ok(TodData {
+        port,
+        bind_index,
+        port_address: PortAddress::from_net_subuni(net, address),
+        nak: command_response == 0xFF,
+        uid_total,
+        block_count,
+        uids,
+    })
+}
+
+impl<'a> TodData<'a> {
+    /// Iterates over the UIDs in this packet
+    pub fn uids(&self) -> impl Iterator<Item = &'a [u8; 6]> + 'a {
+        self.uids
+            .chunks_exact(6)
+            // chunks_exact guarentees that each chunk is 6 bytes
+            .map(|uid| uid.try_into().unwrap())
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        TOD_DATA_HEADER_LEN + self.uids.len() / 6 * 6
+    }
+
+    /// Serializes the ArtTodData into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the ArtTodData into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    ///
+    /// Note: A trailing partial UID in `uids` is not sent
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+        let uid_count = self.uids.len() / 6;
+        let (net, address) = self.port_address.to_net_subuni();
+
+        put_header(&mut buf, OP_TOD_DATA);
+        buf.put_u8(RDM_VERSION);
+        buf.put_u8(self.port);
+        // Spare
+        buf.put_slice(&[0u8; 6]);
+        buf.put_u8(self.bind_index);
+        buf.put_u8(net);
+        // TodFull or TodNak
+        buf.put_u8(if self.nak { 0xFF } else { 0x00 });
+        buf.put_u8(address);
+        buf.put_u16(self.uid_total);
+        buf.put_u8(self.block_count);
+        buf.put_u8(uid_count as u8);
+        buf.put_slice(&self.uids[..uid_count * 6]);
+
+        buf.written()
+    }
+}
@@ -0,0 +1,79 @@
+use nom::{bytes::complete::take, number::complete as number};
+
+use crate::{parse_array, put_header, sink::Counter, ByteSink, PortAddress, SerializeError};
+
+pub(crate) const OP_TOD_REQUEST: u16 = 0x8000;
+
+/// Length of an ArtTodRequest packet excluding the addresses
+pub(crate) const TOD_REQUEST_HEADER_LEN: usize = 24;
+
+/// The maximum number of Port-Addresses a single ArtTodRequest can request
+const MAX_ADDRESSES: usize = 32;
+
+/// ArtTodRequest is sent by a controller to request the Table of Devices (ie. the RDM UIDs discovered) of a set of
+/// DMX ports. Nodes respond with an ArtTodData for each matching port.
+#[derive(Debug)]
+pub struct TodRequest<'a> {
+    /// Bits 14-8 of the Port-Address shared by every requested port
+    pub net: u8,
+    /// The SubUni byte (bits 7-0 of the Port-Address) of each requested port. At most 32 are sent.
+    pub addresses: &'a [u8],
+}
+
+pub(crate) fn parse_tod_request<'a>(s: &'a [u8]) -> Result<TodRequest<'a>, crate::Error<'a>> {
+    // Filler and Spare
+    let (s, _) = parse_array::<9>(s)?;
+    let (s, net) = number::u8(s)?;
+    // The only command is TodFull (0x00)
+    let (s, _command) = number::u8(s)?;
+    let (s, address_count) = number::u8(s)?;
+    let (_s, addresses) = take(address_count)(s)?;
+
+    Ok(TodRequest { net, addresses })
+}
+
+impl<'a> TodRequest<'a> {
+    pub fn new(net: u8, addresses: &'a [u8]) -> Self {
+        Self { net, addresses }
+    }
+
+    /// Iterates over the requested Port-Addresses
+    pub fn port_addresses(&self) -> impl Iterator<Item = PortAddress> + 'a {
+        let net = self.net;
+
+        self.addresses
+            .iter()
+            .map(move |subuni| PortAddress::from_net_subuni(net, *subuni))
+    }
+
+    /// The exact number of bytes written by `serialize`
+    pub fn serialized_len(&self) -> usize {
+        TOD_REQUEST_HEADER_LEN + self.addresses.len().min(MAX_ADDRESSES)
+    }
+
+    /// Serializes the ArtTodRequest into the provided buffer, returning an error instead of panicking if the buffer is too small.
+    pub fn try_serialize(&self, buf: &mut [u8]) -> Result<usize, SerializeError> {
+        ensure_capacity!(buf, self.serialized_len());
+
+        Ok(self.serialize(buf))
+    }
+
+    /// Serializes the ArtTodRequest into the provided sink (eg. a `&mut [u8]`), returning the number of bytes written.
+    ///
+    /// Note: Only the first 32 addresses are sent
+    pub fn serialize<S: ByteSink>(&self, sink: S) -> usize {
+        let mut buf = Counter::new(sink);
+        let addresses = &self.addresses[..self.addresses.len().min(MAX_ADDRESSES)];
+
+        put_header(&mut buf, OP_TOD_REQUEST);
+        // Filler and Spare
+        buf.put_slice(&[0u8; 9]);
+        buf.put_u8(self.net & 0x7F);
+        // TodFull
+        buf.put_u8(0x00);
+        buf.put_u8(addresses.len() as u8);
+        buf.put_slice(addresses);
+
+        buf.written()
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{Dmx, Nzs, PortAddress};
+
+/// The DMX512 start code of ArtDmx data
+const NULL_START_CODE: u8 = 0x00;
+
+/// Access to the universe data carried by both ArtDmx and ArtNzs, so routing code can handle them uniformly.
+pub trait UniverseData {
+    /// The Port-Address of the universe the data is for
+    fn port_address(&self) -> &PortAddress;
+    /// The DMX512 channel data, excluding the start code
+    fn data(&self) -> &[u8];
+    /// The DMX512 start code of the data, always 0x00 for ArtDmx
+    fn start_code(&self) -> u8;
+}
+
+impl<'a> UniverseData for Dmx<'a> {
+    fn port_address(&self) -> &PortAddress {
+        &self.port_address
+    }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
+
+    fn start_code(&self) -> u8 {
+        NULL_START_CODE
+    }
+}
+
+impl<'a> UniverseData for Nzs<'a> {
+    fn port_address(&self) -> &PortAddress {
+        &self.port_address
+    }
+
+    fn data(&self) -> &[u8] {
+        self.data
+    }
+
+    fn start_code(&self) -> u8 {
+        self.start_code
+    }
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "std")]
+
+use std::net::{Ipv4Addr, UdpSocket};
+use std::time::Duration;
+
+use tiny_artnet::{Art, Node, Poll, PollReply};
+
+/// Simulates a controller discovering a node over the loopback interface to check that packets serialized by
+/// one side are parsed correctly by the other.
+#[test]
+fn loopback_discovery() {
+    // Bind both sides to ephemeral ports so this can run alongside a real node on the ArtNet port
+    let node_socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let controller_socket = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+
+    for socket in [&node_socket, &controller_socket] {
+        socket
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+    }
+
+    let node_addr = node_socket.local_addr().unwrap();
+
+    let node = Node::new(PollReply {
+        ip_address: &[127, 0, 0, 1],
+        port: node_addr.port(),
+        firmware_version: 0x0001,
+        short_name: "Loopback Node",
+        long_name: "Tiny Artnet Loopback Node",
+        num_ports: 1,
+        port_types: &[0b10000000, 0, 0, 0],
+        ..Default::default()
+    });
+
+    let mut buf = [0; 1024];
+
+    // Controller: Look for nodes
+    let poll = Poll {
+        flags: 0,
+        min_diagnostic_priority: 0,
+        target_port_addresses: 0..=u16::MAX,
+        targeting_inverted: false,
+        esta_filter: None,
+        oem_filter: None,
+    };
+
+    let msg_len = poll.serialize(&mut buf[..]);
+    controller_socket
+        .send_to(&buf[..msg_len], node_addr)
+        .unwrap();
+
+    // Node: Reply to the ArtPoll
+    let (len, controller_addr) = node_socket.recv_from(&mut buf).unwrap();
+
+    match tiny_artnet::from_slice(&buf[..len]) {
+        Ok(Art::Poll(_)) => {}
+        msg => panic!("Node expected an ArtPoll but received: {:?}", msg),
+    };
+
+    let msg_len = node.reply.serialize(&mut buf[..]);
+    node_socket
+        .send_to(&buf[..msg_len], controller_addr)
+        .unwrap();
+
+    // Controller: Check that the reply matches what the node sent
+    let (len, _) = controller_socket.recv_from(&mut buf).unwrap();
+
+    match tiny_artnet::from_slice(&buf[..len]) {
+        Ok(Art::PollReply(reply)) => {
+            assert_eq!(reply.ip_address, node.reply.ip_address);
+            assert_eq!(reply.port, node.reply.port);
+            assert_eq!(reply.firmware_version, node.reply.firmware_version);
+            assert_eq!(reply.short_name, node.reply.short_name);
+            assert_eq!(reply.long_name, node.reply.long_name);
+            assert_eq!(reply.num_ports, node.reply.num_ports);
+            assert_eq!(reply.port_types, node.reply.port_types);
+        }
+        msg => panic!(
+            "Controller expected an ArtPollReply but received: {:?}",
+            msg
+        ),
+    };
+}